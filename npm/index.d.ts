@@ -0,0 +1,48 @@
+/** Text is taken as UTF-8. */
+export type BytesLike = string | Uint8Array | ArrayBuffer;
+
+export type InitInput =
+  | RequestInfo
+  | URL
+  | Response
+  | BufferSource
+  | WebAssembly.Module;
+
+export interface Codec {
+  encode(data: BytesLike): string;
+  /** Throws when `text` is not valid for the encoding. */
+  decode(text: string): Uint8Array;
+}
+
+export type HashAlgorithm = "md5" | "sha1" | "sha256" | "sha512" | "sha3-256";
+
+export interface DecodedJwt {
+  header: Record<string, unknown>;
+  /** `null` when the payload is not Base64url-encoded JSON. */
+  payload: unknown | null;
+  /** `null` when no secret was given or the token has no signature. */
+  signature: "valid" | "invalid" | null;
+}
+
+export interface God {
+  readonly version: string;
+  base64: Codec;
+  base32: Codec;
+  base58: Codec;
+  /** Returns the lowercase hex digest; throws on an unknown algorithm. */
+  hash(algorithm: HashAlgorithm, data: BytesLike): string;
+  jwt: {
+    /** Verifies an HS256 signature when `secret` is given. */
+    decode(token: string, secret?: string): DecodedJwt;
+  };
+  cron: {
+    /**
+     * The next `n` (default 5, at most 1000) run times of a five-field
+     * cron expression after now, as ISO 8601 UTC strings.
+     */
+    next(expr: string, n?: number): string[];
+  };
+}
+
+/** Loads the wasm module and returns the `god` namespace. */
+export default function load(input?: InitInput | Promise<InitInput>): Promise<God>;
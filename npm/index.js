@@ -0,0 +1,14 @@
+import init, { god } from "./pkg/do_everything_like_a_god.js";
+
+/**
+ * Loads the wasm module and returns the `god` namespace. `input` is passed to
+ * wasm-bindgen's `init`: a URL, Response or bytes of the `.wasm` file; by
+ * default it is fetched next to this file.
+ *
+ * @param {import("./index.d.ts").InitInput} [input]
+ * @returns {Promise<import("./index.d.ts").God>}
+ */
+export default async function load(input) {
+  await init(input === undefined ? undefined : { module_or_path: input });
+  return god();
+}
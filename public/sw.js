@@ -1,44 +1,64 @@
-const CACHE_NAME = 'toolbox-v1';
-const ASSETS = [
-    '/do-everything-like-a-god/',
-    '/do-everything-like-a-god/index.html',
-];
+// The precache list is written by `cargo run --bin precache -- dist` after
+// the build; this placeholder only applies to `trunk serve`.
+const PRECACHE = { "version": "dev", "assets": ["./"] };
+const CACHE_NAME = `toolbox-${PRECACHE.version}`;
 
+// Everything is fetched into a fresh cache before this version installs, so
+// an update is either complete or not used at all.
 self.addEventListener('install', (event) => {
     event.waitUntil(
         caches.open(CACHE_NAME).then((cache) => {
-            return cache.addAll(ASSETS);
+            return cache.addAll(PRECACHE.assets.map((url) => new Request(url, { cache: 'reload' })));
         })
     );
-    self.skipWaiting();
+});
+
+// The page asks a waiting worker to take over once the user accepts the update.
+self.addEventListener('message', (event) => {
+    if (event.data === 'skipWaiting') {
+        self.skipWaiting();
+    }
 });
 
 self.addEventListener('activate', (event) => {
     event.waitUntil(
         caches.keys().then((cacheNames) => {
             return Promise.all(
-                cacheNames.map((cacheName) => {
-                    if (cacheName !== CACHE_NAME) {
-                        return caches.delete(cacheName);
-                    }
-                })
+                cacheNames
+                    .filter((cacheName) => cacheName.startsWith('toolbox-') && cacheName !== CACHE_NAME)
+                    .map((cacheName) => caches.delete(cacheName))
             );
-        })
+        }).then(() => self.clients.claim())
     );
-    self.clients.claim();
 });
 
+// Route pages are precached as `<route>/index.html`.
+function cacheKey(request) {
+    const url = new URL(request.url);
+    if (request.mode === 'navigate' && url.pathname.endsWith('/')) {
+        url.pathname += 'index.html';
+    }
+    return url.href;
+}
+
 self.addEventListener('fetch', (event) => {
+    if (event.request.method !== 'GET') {
+        return;
+    }
     event.respondWith(
-        caches.match(event.request).then((response) => {
-            return response || fetch(event.request).then((fetchResponse) => {
-                return caches.open(CACHE_NAME).then((cache) => {
-                    cache.put(event.request, fetchResponse.clone());
-                    return fetchResponse;
+        caches.open(CACHE_NAME).then((cache) => {
+            return cache.match(cacheKey(event.request), { ignoreSearch: true }).then((response) => {
+                if (response) {
+                    return response;
+                }
+                return fetch(event.request).catch(() => {
+                    // Offline navigation to a route that was not precached.
+                    if (event.request.mode === 'navigate') {
+                        return cache.match('./');
+                    }
+                    return Response.error();
                 });
             });
-        }).catch(() => {
-            return caches.match('/do-everything-like-a-god/');
         })
     );
-});
\ No newline at end of file
+});
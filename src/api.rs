@@ -0,0 +1,171 @@
+//! The JavaScript API. `god()` returns a namespace object built on the same
+//! `tools` functions as the pages:
+//!
+//! ```js
+//! god.base64.encode(bytes)        // string or Uint8Array in, string out
+//! god.base64.decode(text)         // Uint8Array
+//! god.hash("sha256", data)        // hex digest
+//! god.jwt.decode(token, secret?)  // { header, payload, signature }
+//! god.cron.next(expr, n?)         // ISO 8601 UTC strings
+//! ```
+//!
+//! Errors are thrown as `Error`s with the English message. The npm package in
+//! `npm/` wraps this with TypeScript definitions.
+
+use crate::tools::crypto::{jwt_decode, HashAlgorithm, SignatureStatus};
+use crate::tools::encoding;
+use crate::tools::utilities::cron_next;
+use crate::tools::ToolError;
+use js_sys::{Array, Object, Reflect, Uint8Array, JSON};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// How many runs `cron.next` returns when `n` is left out.
+pub const DEFAULT_CRON_RUNS: usize = 5;
+
+/// The most runs `cron.next` returns.
+pub const MAX_CRON_RUNS: usize = 1000;
+
+fn throw(e: ToolError) -> JsValue {
+    js_sys::Error::new(&e.to_string()).into()
+}
+
+/// Strings are taken as UTF-8; anything else must be a `Uint8Array` or an
+/// `ArrayBuffer`.
+fn bytes(data: &JsValue) -> Result<Vec<u8>, JsValue> {
+    if let Some(text) = data.as_string() {
+        Ok(text.into_bytes())
+    } else if data.is_instance_of::<Uint8Array>() || data.is_instance_of::<js_sys::ArrayBuffer>() {
+        Ok(Uint8Array::new(data).to_vec())
+    } else {
+        Err(js_sys::TypeError::new("expected a string, Uint8Array or ArrayBuffer").into())
+    }
+}
+
+fn text(value: &JsValue) -> Result<String, JsValue> {
+    value
+        .as_string()
+        .ok_or_else(|| js_sys::TypeError::new("expected a string").into())
+}
+
+fn set(target: &Object, key: &str, value: &JsValue) {
+    Reflect::set(target, &key.into(), value).expect("setting a property on a plain object");
+}
+
+fn function<F>(f: F) -> JsValue
+where
+    F: Fn(JsValue, JsValue) -> Result<JsValue, JsValue> + 'static,
+{
+    Closure::<dyn Fn(JsValue, JsValue) -> Result<JsValue, JsValue>>::new(f).into_js_value()
+}
+
+fn codec(encode: fn(&[u8]) -> String, decode: fn(&str) -> Result<Vec<u8>, ToolError>) -> Object {
+    let codec = Object::new();
+    set(
+        &codec,
+        "encode",
+        &function(move |data, _| Ok(encode(&bytes(&data)?).into())),
+    );
+    set(
+        &codec,
+        "decode",
+        &function(move |input, _| {
+            let decoded = decode(&text(&input)?).map_err(throw)?;
+            Ok(Uint8Array::from(decoded.as_slice()).into())
+        }),
+    );
+    codec
+}
+
+fn jwt() -> Object {
+    let jwt = Object::new();
+    set(
+        &jwt,
+        "decode",
+        &function(|token, secret| {
+            let secret = secret.as_string().unwrap_or_default();
+            let decoded = jwt_decode(&text(&token)?, &secret).map_err(throw)?;
+            let result = Object::new();
+            set(&result, "header", &JSON::parse(&decoded.header)?);
+            let payload = match decoded.payload {
+                Some(payload) => JSON::parse(&payload)?,
+                None => JsValue::NULL,
+            };
+            set(&result, "payload", &payload);
+            let signature = match decoded.signature {
+                Some(SignatureStatus::Valid) => "valid".into(),
+                Some(SignatureStatus::Invalid) => "invalid".into(),
+                None => JsValue::NULL,
+            };
+            set(&result, "signature", &signature);
+            Ok(result.into())
+        }),
+    );
+    jwt
+}
+
+fn cron() -> Object {
+    let cron = Object::new();
+    set(
+        &cron,
+        "next",
+        &function(|expr, n| {
+            let count = match n.as_f64() {
+                Some(n) if n.fract() == 0.0 && (0.0..=MAX_CRON_RUNS as f64).contains(&n) => {
+                    n as usize
+                }
+                Some(_) => {
+                    let message = format!("n must be a whole number from 0 to {MAX_CRON_RUNS}");
+                    return Err(js_sys::RangeError::new(&message).into());
+                }
+                None => DEFAULT_CRON_RUNS,
+            };
+            let runs = cron_next(&text(&expr)?, chrono::Utc::now(), count).map_err(throw)?;
+            Ok(runs
+                .iter()
+                .map(|t| JsValue::from(t.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)))
+                .collect::<Array>()
+                .into())
+        }),
+    );
+    cron
+}
+
+/// Builds the `god` namespace object.
+#[wasm_bindgen(js_name = god)]
+pub fn namespace() -> Object {
+    let god = Object::new();
+    set(&god, "version", &env!("CARGO_PKG_VERSION").into());
+    set(
+        &god,
+        "base64",
+        &codec(encoding::base64_encode, encoding::base64_decode),
+    );
+    set(
+        &god,
+        "base32",
+        &codec(encoding::base32_encode, encoding::base32_decode),
+    );
+    set(
+        &god,
+        "base58",
+        &codec(encoding::base58_encode, encoding::base58_decode),
+    );
+    set(
+        &god,
+        "hash",
+        &function(|algorithm, data| {
+            let id = text(&algorithm)?;
+            let algorithm = HashAlgorithm::from_id(&id).ok_or_else(|| {
+                throw(ToolError::InvalidOption {
+                    key: "algorithm".into(),
+                    value: id,
+                })
+            })?;
+            Ok(algorithm.digest(&bytes(&data)?).into())
+        }),
+    );
+    set(&god, "jwt", &jwt());
+    set(&god, "cron", &cron());
+    god
+}
@@ -1,3 +1,17 @@
+use crate::registry::{self, Category};
+use crate::tools::bytes::DumpLayout;
+use crate::tools::history::{History, HistorySettings};
+use crate::tools::job::{self, Job, JobOutput};
+use crate::tools::pipeline::{Recipe, Step};
+use crate::tools::settings::{
+    self, Bundle, ImportMode, HISTORY_SETTINGS_KEY, LANG_KEY, RECIPES_KEY, THEME_KEY,
+};
+use crate::tools::{
+    self, bytes, crypto, detect, development, encoding, permalink, search, utilities, Lang,
+    OptionKind, Options, Tool, ToolError,
+};
+use crate::worker::JobRunner;
+use leptos::leptos_dom::HydrationCtx;
 use leptos::*;
 use leptos_meta::*;
 use leptos_router::*;
@@ -5,42 +19,249 @@ use std::sync::OnceLock;
 use wasm_bindgen::JsCast;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum Lang {
-    En,
-    Zh,
-}
-
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum Theme {
+pub enum Theme {
+    /// Follows the OS `prefers-color-scheme`.
+    System,
     Dark,
     Light,
+    HighContrast,
+    SolarizedDark,
+    SolarizedLight,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 6] = [
+        Theme::System,
+        Theme::Dark,
+        Theme::Light,
+        Theme::HighContrast,
+        Theme::SolarizedDark,
+        Theme::SolarizedLight,
+    ];
+
+    /// Stored in localStorage and used in the `theme-<code>` layout class.
+    pub fn code(self) -> &'static str {
+        match self {
+            Theme::System => "system",
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+            Theme::HighContrast => "high-contrast",
+            Theme::SolarizedDark => "solarized-dark",
+            Theme::SolarizedLight => "solarized-light",
+        }
+    }
+
+    fn from_code(code: &str) -> Option<Theme> {
+        Theme::ALL.into_iter().find(|t| t.code() == code)
+    }
+
+    /// Catalog id of the theme's name.
+    fn name_id(self) -> &'static str {
+        match self {
+            Theme::System => "theme.system",
+            Theme::Dark => "theme.dark",
+            Theme::Light => "theme.light",
+            Theme::HighContrast => "theme.high-contrast",
+            Theme::SolarizedDark => "theme.solarized-dark",
+            Theme::SolarizedLight => "theme.solarized-light",
+        }
+    }
+
+    /// The theme whose CSS variables apply, with `System` resolved.
+    fn resolve(self, prefers_light: bool) -> Theme {
+        match self {
+            Theme::System if prefers_light => Theme::Light,
+            Theme::System => Theme::Dark,
+            theme => theme,
+        }
+    }
+}
+
+/// Keeps `prefers_light` in sync with the OS color scheme.
+fn track_prefers_light(prefers_light: RwSignal<bool>) {
+    use wasm_bindgen::closure::Closure;
+
+    let Some(query) = browser_window()
+        .and_then(|w| w.match_media("(prefers-color-scheme: light)").ok())
+        .flatten()
+    else {
+        return;
+    };
+    prefers_light.set(query.matches());
+    let on_change = Closure::<dyn FnMut(web_sys::MediaQueryListEvent)>::new(
+        move |ev: web_sys::MediaQueryListEvent| prefers_light.set(ev.matches()),
+    );
+    let _ = query.add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref());
+    on_change.forget();
+}
+
+#[cfg(test)]
+mod theme_tests {
+    use super::Theme;
+
+    #[test]
+    fn test_theme_code_round_trip() {
+        for theme in Theme::ALL {
+            assert_eq!(Theme::from_code(theme.code()), Some(theme));
+        }
+        assert_eq!(Theme::from_code("light"), Some(Theme::Light));
+        assert_eq!(Theme::from_code("sepia"), None);
+    }
+
+    #[test]
+    fn test_system_theme_follows_preference() {
+        assert_eq!(Theme::System.resolve(true), Theme::Light);
+        assert_eq!(Theme::System.resolve(false), Theme::Dark);
+        assert_eq!(Theme::SolarizedDark.resolve(true), Theme::SolarizedDark);
+    }
+}
+
+/// The browser window; `None` when the app is pre-rendered on the host.
+fn browser_window() -> Option<web_sys::Window> {
+    if cfg!(target_arch = "wasm32") {
+        web_sys::window()
+    } else {
+        None
+    }
+}
+
+/// Stands in for the browser when the app is pre-rendered on the host.
+#[derive(Clone, Debug)]
+pub struct PrerenderContext {
+    /// The deployed root, e.g. `https://example.com/app/`.
+    pub site_url: String,
+    pub lang: Lang,
 }
 
 fn copy_to_clipboard(text: &str) {
-    if let Some(window) = web_sys::window() {
+    if let Some(window) = browser_window() {
         let clipboard = window.navigator().clipboard();
         let _ = clipboard.write_text(text);
     }
 }
 
-fn setup_keyboard_shortcuts() {
-    if let Some(window) = web_sys::window() {
-        let closure =
-            wasm_bindgen::closure::Closure::wrap(Box::new(move |event: web_sys::KeyboardEvent| {
-                if event.ctrl_key() || event.meta_key() && event.key().as_str() == "k" {
-                    event.prevent_default();
-                }
-            }) as Box<dyn FnMut(_)>);
-        let _ =
-            window.add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
-        closure.forget();
+fn local_storage() -> Option<web_sys::Storage> {
+    browser_window()?.local_storage().ok().flatten()
+}
+
+fn load_stored<T: serde::de::DeserializeOwned + Default>(key: &str) -> T {
+    local_storage()
+        .and_then(|storage| storage.get_item(key).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_stored<T: serde::Serialize + ?Sized>(key: &str, value: &T) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(key, &serde_json::to_string(value).unwrap());
+    }
+}
+
+/// A tool's remembered options.
+fn load_prefs(tool: &dyn Tool) -> Options {
+    load_stored(&settings::prefs_key(tool.id()))
+}
+
+fn save_prefs(tool: &dyn Tool, options: &Options) {
+    save_stored(&settings::prefs_key(tool.id()), options)
+}
+
+/// The site's localStorage entries; see `settings::is_site_key`.
+fn stored_site_entries() -> Vec<(String, String)> {
+    let Some(storage) = local_storage() else {
+        return Vec::new();
+    };
+    let len = storage.length().unwrap_or(0);
+    (0..len)
+        .filter_map(|i| storage.key(i).ok().flatten())
+        .filter(|key| settings::is_site_key(key))
+        .filter_map(|key| {
+            let value = storage.get_item(&key).ok().flatten()?;
+            Some((key, value))
+        })
+        .collect()
+}
+
+/// Removes the site's localStorage entries, leaving other sites' alone.
+fn clear_site_storage() {
+    if let Some(storage) = local_storage() {
+        for (key, _) in stored_site_entries() {
+            let _ = storage.remove_item(&key);
+        }
+    }
+}
+
+fn download_file(filename: &str, data: &[u8], mime: &str) {
+    let Some(document) = browser_window().and_then(|w| w.document()) else {
+        return;
+    };
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(data));
+    let bag = web_sys::BlobPropertyBag::new();
+    bag.set_type(mime);
+    let Ok(blob) = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &bag) else {
+        return;
+    };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+        return;
+    };
+    if let Ok(anchor) = document.create_element("a") {
+        let anchor: web_sys::HtmlAnchorElement = anchor.unchecked_into();
+        anchor.set_href(&url);
+        anchor.set_download(filename);
+        anchor.click();
     }
+    let _ = web_sys::Url::revoke_object_url(&url);
 }
 
+async fn read_blob(blob: &web_sys::Blob) -> Option<Vec<u8>> {
+    let buffer = wasm_bindgen_futures::JsFuture::from(blob.array_buffer())
+        .await
+        .ok()?;
+    Some(js_sys::Uint8Array::new(&buffer).to_vec())
+}
+
+/// Handlers of the current page that the command palette can invoke.
+#[derive(Clone, Copy)]
+struct PageActions {
+    clear: RwSignal<Option<(usize, Callback<()>)>>,
+    output: RwSignal<Option<(usize, Signal<String>)>>,
+}
+
+impl PageActions {
+    fn new() -> Self {
+        Self {
+            clear: create_rw_signal(None),
+            output: create_rw_signal(None),
+        }
+    }
+}
+
+/// Puts `value` into `slot` until the calling component is cleaned up.
+fn register_action<T: 'static>(slot: RwSignal<Option<(usize, T)>>, value: T) {
+    static NEXT_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let id = NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    slot.set(Some((id, value)));
+    on_cleanup(move || {
+        if slot.with_untracked(|s| s.as_ref().is_some_and(|(i, _)| *i == id)) {
+            slot.set(None);
+        }
+    });
+}
+
+/// `primary` marks the page's main output, which the palette can copy.
 #[component]
-fn CopyButton(text: ReadSignal<String>) -> impl IntoView {
+fn CopyButton(
+    #[prop(into)] text: Signal<String>,
+    #[prop(optional)] primary: bool,
+) -> impl IntoView {
     let (copied, set_copied) = create_signal(false);
 
+    if primary {
+        if let Some(actions) = use_context::<PageActions>() {
+            register_action(actions.output, text);
+        }
+    }
+
     view! {
         <button
             class="copy-btn"
@@ -55,35 +276,598 @@ fn CopyButton(text: ReadSignal<String>) -> impl IntoView {
     }
 }
 
+/// Saves `text` as `filename`.
+#[component]
+fn DownloadButton(#[prop(into)] text: Signal<String>, filename: &'static str) -> impl IntoView {
+    view! {
+        <button
+            class="copy-btn"
+            title=filename
+            disabled=move || text.with(String::is_empty)
+            on:click=move |_| download_file(filename, text.get_untracked().as_bytes(), "text/plain")
+        >
+            "⬇️"
+        </button>
+    }
+}
+
+/// Hands files dropped onto or pasted into `children` to `on_file`. Pasted
+/// text is left to the browser.
+#[component]
+fn FileDrop(#[prop(into)] on_file: Callback<web_sys::File>, children: Children) -> impl IntoView {
+    let (dragging, set_dragging) = create_signal(false);
+
+    let on_drop = move |ev: ev::DragEvent| {
+        ev.prevent_default();
+        set_dragging.set(false);
+        if let Some(file) = ev
+            .data_transfer()
+            .and_then(|dt| dt.files())
+            .and_then(|f| f.get(0))
+        {
+            on_file.call(file);
+        }
+    };
+
+    let on_paste = move |ev: ev::Event| {
+        let ev: web_sys::ClipboardEvent = ev.unchecked_into();
+        if let Some(file) = ev
+            .clipboard_data()
+            .and_then(|dt| dt.files())
+            .and_then(|f| f.get(0))
+        {
+            ev.prevent_default();
+            on_file.call(file);
+        }
+    };
+
+    view! {
+        <div
+            class=move || if dragging.get() { "drop-zone dragging" } else { "drop-zone" }
+            on:dragover=move |ev: ev::DragEvent| { ev.prevent_default(); set_dragging.set(true); }
+            on:dragleave=move |_| set_dragging.set(false)
+            on:drop=on_drop
+            on:paste=on_paste
+        >
+            {children()}
+        </div>
+    }
+}
+
+/// A textarea that also takes a dropped, pasted or picked file as its text.
+/// Files that are not UTF-8 are rejected with an error rather than mangled.
+#[component]
+fn TextInput(
+    lang: ReadSignal<Lang>,
+    #[prop(into)] value: Signal<String>,
+    #[prop(into)] on_input: Callback<String>,
+    #[prop(optional)] placeholder: &'static str,
+) -> impl IntoView {
+    let picker = create_node_ref::<html::Input>();
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
+    let load = move |file: web_sys::File| {
+        set_error.set(None);
+        spawn_local(async move {
+            let text = read_blob(&file)
+                .await
+                .ok_or_else(|| ToolError::Read(file.name()))
+                .and_then(|data| {
+                    String::from_utf8(data).map_err(|_| ToolError::NotText(file.name()))
+                });
+            match text {
+                Ok(text) => on_input.call(text),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    view! {
+        <FileDrop on_file=load>
+            <div class="text-input">
+                <textarea
+                    prop:value=value
+                    on:input=move |ev| { set_error.set(None); on_input.call(event_target_value(&ev)); }
+                    placeholder=if placeholder.is_empty() { "..." } else { placeholder }
+                ></textarea>
+                <button class="pick-btn" title="📂" on:click=move |_| {
+                    if let Some(input) = picker.get() {
+                        input.click();
+                    }
+                }>"📂"</button>
+                <input type="file" hidden node_ref=picker on:change=move |ev| {
+                    let target = event_target::<web_sys::HtmlInputElement>(&ev);
+                    if let Some(file) = target.files().and_then(|files| files.get(0)) {
+                        load(file);
+                    }
+                    target.set_value("");
+                }/>
+            </div>
+            {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
+        </FileDrop>
+    }
+}
+
+type StateField = (&'static str, Signal<String>, SignalSetter<String>);
+
+fn state_field(
+    key: &'static str,
+    value: impl Into<Signal<String>>,
+    restore: impl Into<SignalSetter<String>>,
+) -> StateField {
+    (key, value.into(), restore.into())
+}
+
+fn snapshot(fields: &[StateField]) -> permalink::State {
+    fields
+        .iter()
+        .map(|(key, value, _)| (key.to_string(), value.get()))
+        .filter(|(_, value)| !value.is_empty())
+        .collect()
+}
+
+/// Two fields for a byte buffer: `key` holds it when it is printable text,
+/// `hex_key` as hex otherwise.
+fn byte_state(
+    key: &'static str,
+    hex_key: &'static str,
+    bytes: RwSignal<Vec<u8>>,
+) -> [StateField; 2] {
+    [
+        state_field(
+            key,
+            Signal::derive(move || {
+                bytes.with(|b| bytes::as_text(b).map(str::to_string).unwrap_or_default())
+            }),
+            SignalSetter::map(move |text: String| bytes.set(text.into_bytes())),
+        ),
+        state_field(
+            hex_key,
+            Signal::derive(move || {
+                bytes.with(|b| {
+                    if bytes::as_text(b).is_some() {
+                        String::new()
+                    } else {
+                        bytes::to_hex(b)
+                    }
+                })
+            }),
+            SignalSetter::map(move |hex: String| {
+                if let Ok(b) = bytes::parse_hex(&hex) {
+                    bytes.set(b);
+                }
+            }),
+        ),
+    ]
+}
+
+/// Restores `fields` from a permalink in the URL fragment, and on click puts
+/// their current values into the fragment and copies the link.
+#[component]
+fn ShareButton(fields: Vec<StateField>) -> impl IntoView {
+    let (copied, set_copied) = create_signal(false);
+
+    // The router's location rather than the window's: on client-side
+    // navigation the page renders before the browser URL is updated.
+    let hash = use_location().hash.get_untracked();
+    if let Ok(state) = permalink::decode(&hash) {
+        for (key, _, restore) in &fields {
+            if let Some(value) = state.get(*key) {
+                restore.set(value.clone());
+            }
+        }
+    }
+
+    let share = move |_| {
+        let state = snapshot(&fields);
+        let Some(window) = browser_window() else {
+            return;
+        };
+        let fragment = format!("#{}", permalink::encode(&state));
+        if let Ok(history) = window.history() {
+            let _ =
+                history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&fragment));
+        }
+        if let Ok(href) = window.location().href() {
+            copy_to_clipboard(&href);
+            set_copied.set(true);
+            set_timeout(
+                move || set_copied.set(false),
+                std::time::Duration::from_millis(2000),
+            );
+        }
+    };
+
+    view! {
+        <button class="copy-btn share-btn" title="Copy link" on:click=share>
+            {move || if copied.get() { "✓" } else { "🔗" }}
+        </button>
+    }
+}
+
 #[component]
 fn ClearButton(on_click: impl Fn() + 'static) -> impl IntoView {
+    let on_click = Callback::new(move |()| on_click());
+    if let Some(actions) = use_context::<PageActions>() {
+        register_action(actions.clear, on_click);
+    }
+
     view! {
-        <button class="clear-btn" on:click=move |_| on_click()>
+        <button class="clear-btn" on:click=move |_| on_click.call(())>
             "🗑️"
         </button>
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum ByteMode {
+    Text,
+    Hex,
+    File,
+}
+
+/// Only this much of a binary buffer is rendered as a hex dump.
+const HEX_DUMP_LIMIT: usize = 16 * 1024;
+
+/// Edits a byte buffer as text, hex or a loaded file. Bytes that are not
+/// printable text are shown as a hex dump and can be downloaded.
+#[component]
+fn ByteField(lang: ReadSignal<Lang>, bytes: RwSignal<Vec<u8>>) -> impl IntoView {
+    let mode = create_rw_signal(ByteMode::Text);
+    let hex_draft = create_rw_signal(String::new());
+    let (hex_error, set_hex_error) = create_signal(Option::<ToolError>::None);
+    let (file_name, set_file_name) = create_signal(String::new());
+
+    // Keep the hex draft in step with changes made elsewhere, without
+    // reformatting what is being typed.
+    create_effect(move |_| {
+        let current = bytes.get();
+        if bytes::parse_hex(&hex_draft.get_untracked()).ok().as_ref() != Some(&current) {
+            hex_draft.set(bytes::to_hex(&current));
+            set_hex_error.set(None);
+        }
+    });
+
+    let on_hex_input = move |ev: ev::Event| {
+        let value = event_target_value(&ev);
+        hex_draft.set(value.clone());
+        match bytes::parse_hex(&value) {
+            Ok(b) => {
+                set_hex_error.set(None);
+                bytes.set(b);
+            }
+            Err(e) => set_hex_error.set(Some(e)),
+        }
+    };
+
+    let load_file = move |file: web_sys::File| {
+        set_file_name.set(file.name());
+        spawn_local(async move {
+            if let Some(data) = read_blob(&file).await {
+                bytes.set(data);
+            }
+        });
+    };
+
+    let on_file_change = move |ev: ev::Event| {
+        let target = event_target::<web_sys::HtmlInputElement>(&ev);
+        if let Some(file) = target.files().and_then(|files| files.get(0)) {
+            load_file(file);
+        }
+    };
+
+    let mode_button = move |m: ByteMode, id: &'static str| {
+        view! {
+            <button
+                class=move || if mode.get() == m { "mode-btn active" } else { "mode-btn" }
+                on:click=move |_| mode.set(m)
+            >
+                {move || lang.get().t(id)}
+            </button>
+        }
+    };
+
+    let dump = move || {
+        bytes.with(|b| {
+            let mut dump = bytes::hex_dump(&b[..b.len().min(HEX_DUMP_LIMIT)]);
+            if b.len() > HEX_DUMP_LIMIT {
+                dump.push('…');
+            }
+            dump
+        })
+    };
+
+    view! {
+        <FileDrop on_file=load_file>
+            <div class="byte-modes">
+                {mode_button(ByteMode::Text, "bytes.text")}
+                {mode_button(ByteMode::Hex, "bytes.hex")}
+                {mode_button(ByteMode::File, "bytes.file")}
+            </div>
+            {move || match mode.get() {
+                ByteMode::Text => match bytes.with(|b| bytes::as_text(b).map(str::to_string)) {
+                    Some(text) => view! {
+                        <textarea
+                            prop:value=text
+                            on:input=move |ev| bytes.set(event_target_value(&ev).into_bytes())
+                            placeholder="..."
+                        ></textarea>
+                    }.into_view(),
+                    None => view! {
+                        <div class="byte-note">
+                            {move || lang.get().format("bytes.binary", &[("size", &job::format_size(bytes.with(Vec::len) as f64))])}
+                        </div>
+                        <pre class="hex-dump">{dump}</pre>
+                    }.into_view(),
+                },
+                ByteMode::Hex => view! {
+                    <textarea class="hex-input" prop:value=hex_draft on:input=on_hex_input placeholder="48 65 6c 6c 6f"></textarea>
+                    {move || hex_error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
+                }.into_view(),
+                ByteMode::File => view! {
+                    <input type="file" on:change=on_file_change class="file-input"/>
+                    {move || (!file_name.get().is_empty()).then(|| view! { <div class="byte-note">{file_name}</div> })}
+                    <pre class="hex-dump">{dump}</pre>
+                }.into_view(),
+            }}
+            <div class="byte-footer">
+                <span class="byte-note">{move || lang.get().format("bytes.size", &[("count", &bytes.with(Vec::len).to_string())])}</span>
+                <button
+                    class="copy-btn"
+                    title=move || lang.get().t("bytes.download")
+                    disabled=move || bytes.with(Vec::is_empty)
+                    on:click=move |_| download_file("output.bin", &bytes.get_untracked(), "application/octet-stream")
+                >
+                    "⬇️"
+                </button>
+            </div>
+        </FileDrop>
+    }
+}
+
+/// Progress and a cancel button while `runner` has a job in flight.
+#[component]
+fn JobStatus(lang: ReadSignal<Lang>, runner: JobRunner) -> impl IntoView {
+    move || {
+        runner.busy().then(|| {
+            let progress = runner.progress().filter(|&(_, total)| total > 0);
+            let rate = runner.throughput();
+            view! {
+                <div class="job-status">
+                    <span class="loading">{lang.get().t("job.running")}</span>
+                    {progress.map(|(done, total)| view! {
+                        <progress max=total.to_string() value=done.to_string()></progress>
+                        <span>{format!("{}% · {} / {}", done * 100 / total, job::format_size(done as f64), job::format_size(total as f64))}</span>
+                    })}
+                    {rate.map(|r| view! { <span class="job-rate">{format!("{}/s", job::format_size(r))}</span> })}
+                    <button class="clear-btn" on:click=move |_| runner.cancel()>{lang.get().t("job.cancel")}</button>
+                </div>
+            }
+        })
+    }
+}
+
+/// How long the page state has to stay unchanged before it is recorded.
+const HISTORY_DEBOUNCE_MS: u64 = 1500;
+
+fn history_key(tool: &dyn Tool) -> String {
+    settings::history_key(tool.id())
+}
+
+fn format_time(timestamp: i64) -> String {
+    js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(timestamp as f64 * 1000.0))
+        .to_locale_string("default", &wasm_bindgen::JsValue::UNDEFINED)
+        .into()
+}
+
+fn history_preview(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.char_indices().nth(60) {
+        Some((i, _)) => format!("{}…", &text[..i]),
+        None => text,
+    }
+}
+
+/// Records `fields` and `output` into the tool's history once they settle,
+/// and lists the entries for restoring, pinning and deleting while `open`.
+/// Pages whose state has an `output` field need not pass `output`.
+#[component]
+fn HistoryPanel(
+    lang: ReadSignal<Lang>,
+    tool: &'static dyn Tool,
+    fields: Vec<StateField>,
+    output: Option<Signal<String>>,
+    open: ReadSignal<bool>,
+) -> impl IntoView {
+    let settings = create_rw_signal(load_stored::<HistorySettings>(HISTORY_SETTINGS_KEY));
+    let history = create_rw_signal(load_stored::<History>(&history_key(tool)));
+    let generation = store_value(0u32);
+    let fields = store_value(fields);
+
+    let save = move |h: &History| save_stored(&history_key(tool), h);
+
+    create_effect(move |_| {
+        let state = fields.with_value(|f| snapshot(f));
+        let output = output.map(|o| o.get()).unwrap_or_default();
+        if state.is_empty() || !settings.with(|s| s.is_enabled(tool)) {
+            return;
+        }
+        generation.update_value(|g| *g += 1);
+        let current = generation.get_value();
+        set_timeout(
+            move || {
+                if generation.get_value() == current {
+                    history.update(|h| {
+                        h.record(state, output, development::now_timestamp());
+                        save(h);
+                    });
+                }
+            },
+            std::time::Duration::from_millis(HISTORY_DEBOUNCE_MS),
+        );
+    });
+
+    let restore = move |state: permalink::State| {
+        fields.with_value(|f| {
+            for (key, _, restore) in f {
+                restore.set(state.get(*key).cloned().unwrap_or_default());
+            }
+        })
+    };
+
+    let set_setting = move |all: bool, enabled: bool| {
+        settings.update(|s| {
+            if all {
+                s.enabled = enabled;
+            } else {
+                s.set_enabled(tool, enabled);
+            }
+            save_stored(HISTORY_SETTINGS_KEY, s);
+        })
+    };
+
+    view! {
+        <Show when=move || open.get()>
+            <div class="box history-panel">
+                <div class="box-header">
+                    <div class="box-label">{move || lang.get().t("history.title")}</div>
+                    <button class="clear-btn" title="Clear history" on:click=move |_| history.update(|h| { h.clear(); save(h); })>"🗑️"</button>
+                </div>
+                <div class="btn-row history-settings">
+                    <label>
+                        <input type="checkbox" prop:checked=move || settings.with(|s| s.enabled)
+                            on:change=move |ev| set_setting(true, event_target_checked(&ev))/>
+                        {move || lang.get().t("history.all-tools")}
+                    </label>
+                    <label>
+                        <input type="checkbox" prop:checked=move || settings.with(|s| s.is_enabled(tool))
+                            on:change=move |ev| set_setting(false, event_target_checked(&ev))/>
+                        {move || lang.get().t(if tool.sensitive() { "history.this-tool-sensitive" } else { "history.this-tool" })}
+                    </label>
+                </div>
+                <div class="history-list">
+                    {move || history.with(|h| {
+                        if h.entries().is_empty() {
+                            return view! {
+                                <div class="history-empty">{move || lang.get().t("history.empty")}</div>
+                            }.into_view();
+                        }
+                        h.entries().iter().cloned().enumerate().map(|(i, entry)| {
+                            let input = entry
+                                .state
+                                .get("input")
+                                .or_else(|| entry.state.values().next())
+                                .map(|v| history_preview(v))
+                                .unwrap_or_default();
+                            let output = Some(entry.output.as_str())
+                                .filter(|o| !o.is_empty())
+                                .or_else(|| entry.state.get("output").map(String::as_str))
+                                .map(history_preview);
+                            let state = entry.state;
+                            view! {
+                                <div class="history-entry" class:pinned=entry.pinned>
+                                    <button class="history-restore" title="Restore" on:click=move |_| restore(state.clone())>
+                                        <span class="history-time">{format_time(entry.timestamp)}</span>
+                                        <span class="history-preview">{input}</span>
+                                        {output.map(|o| view! { <span class="history-output">{o}</span> })}
+                                    </button>
+                                    <button class="clear-btn" title="Pin" on:click=move |_| history.update(|h| { h.toggle_pin(i); save(h); })>
+                                        {if entry.pinned { "📌" } else { "📍" }}
+                                    </button>
+                                    <button class="clear-btn" title="Delete" on:click=move |_| history.update(|h| { h.remove(i); save(h); })>"✕"</button>
+                                </div>
+                            }
+                        }).collect_view()
+                    })}
+                </div>
+            </div>
+        </Show>
+    }
+}
+
 #[component]
 fn ToolHeader(
     lang: ReadSignal<Lang>,
-    title_en: &'static str,
-    title_zh: &'static str,
+    tool: &'static dyn Tool,
+    #[prop(optional)] state: Option<Vec<StateField>>,
+    #[prop(optional, into)] output: Option<Signal<String>>,
 ) -> impl IntoView {
+    let (history_open, set_history_open) = create_signal(false);
+
     view! {
-        <h2 style="font-size:3rem;font-weight:900;margin:0">
-            {move || match lang.get() {
-                Lang::En => title_en,
-                Lang::Zh => title_zh,
-            }}
-        </h2>
+        <div class="tool-title">
+            <h2 style="font-size:3rem;font-weight:900;margin:0">
+                {move || tool.name(lang.get())}
+            </h2>
+            {state.clone().map(|fields| view! {
+                <div class="btn-row">
+                    <ShareButton fields=fields/>
+                    <button class="copy-btn" title="History" class:active=history_open
+                        on:click=move |_| set_history_open.update(|open| *open = !*open)>"🕘"</button>
+                </div>
+            })}
+        </div>
+        {state.map(|fields| view! { <HistoryPanel lang=lang tool=tool fields=fields output=output open=history_open/> })}
     }
 }
 
+/// A select for each of `keys`, which must be `Choice` options of `tool`.
+/// Labels come from the `option.<key>` and `option.<key>.<value>` catalog
+/// entries; changes are remembered for the tool.
+#[component]
+fn ToolOptions(
+    lang: ReadSignal<Lang>,
+    tool: &'static dyn Tool,
+    options: RwSignal<Options>,
+    keys: &'static [&'static str],
+) -> impl IntoView {
+    let label =
+        move |id: String, fallback: &'static str| lang.get().lookup(&id).unwrap_or(fallback);
+    tool.options()
+        .iter()
+        .filter(|spec| keys.contains(&spec.key))
+        .filter_map(|spec| match spec.kind {
+            OptionKind::Choice(choices) => Some((spec, choices)),
+            _ => None,
+        })
+        .map(|(spec, choices)| {
+            let key = spec.key;
+            let current =
+                move || options.with(|o| o.value(spec).unwrap_or(spec.default).to_string());
+            view! {
+                <label class="pipeline-option">
+                    <span>{move || label(format!("option.{key}"), key)}</span>
+                    <select class="base-select" on:change=move |ev| {
+                        options.update(|o| o.set(key, event_target_value(&ev)));
+                        options.with_untracked(|o| save_prefs(tool, o));
+                    }>
+                        {choices.iter().map(|c| view! {
+                            <option value=*c selected=move || current() == *c>
+                                {move || label(format!("option.{key}.{c}"), c)}
+                            </option>
+                        }).collect_view()}
+                    </select>
+                </label>
+            }
+        })
+        .collect_view()
+}
+
 const ROUTER_BASE: &str = "/do-everything-like-a-god";
 
+/// Router base from the document's `<base href>` (or the pre-rendered site
+/// URL), so the app can be hosted under any path; `ROUTER_BASE` when there is
+/// none.
 fn resolve_router_base() -> &'static str {
-    ROUTER_BASE
+    static BASE: OnceLock<String> = OnceLock::new();
+    BASE.get_or_init(|| {
+        browser_window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.query_selector("base[href]").ok().flatten())
+            .and_then(|base| base.get_attribute("href"))
+            .or_else(|| use_context::<PrerenderContext>().map(|host| host.site_url))
+            .map(|href| parse_router_base(&href))
+            .unwrap_or_else(|| ROUTER_BASE.to_string())
+    })
 }
 
 fn parse_router_base(base_href: &str) -> String {
@@ -157,58 +941,87 @@ mod router_base_tests {
     }
 }
 
+/// The saved language, else the browser's (or the pre-rendered one), else
+/// English.
+fn initial_lang() -> Lang {
+    local_storage()
+        .and_then(|storage| storage.get_item(LANG_KEY).ok().flatten())
+        .and_then(|code| Lang::from_code(&code))
+        .or_else(|| {
+            let tag = browser_window()?.navigator().language()?;
+            Some(Lang::negotiate(&tag))
+        })
+        .or_else(|| use_context::<PrerenderContext>().map(|host| host.lang))
+        .unwrap_or(Lang::En)
+}
+
+/// The `<html lang>` the `prerender` step wrote.
+fn prerendered_lang() -> Option<Lang> {
+    let code = browser_window()?
+        .document()?
+        .document_element()?
+        .get_attribute("lang")?;
+    Lang::from_code(&code)
+}
+
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
-    let (lang, set_lang) = create_signal(Lang::Zh);
-    let (theme, set_theme) = create_signal(Theme::Dark);
+    let saved_theme = || {
+        local_storage()
+            .and_then(|storage| storage.get_item(THEME_KEY).ok().flatten())
+            .and_then(|code| Theme::from_code(&code))
+            .unwrap_or(Theme::System)
+    };
+    let prefers_light = create_rw_signal(false);
+    // Hydration has to start from what was pre-rendered: its language and the
+    // default theme. The visitor's own choices follow once it is done.
+    let (lang, set_lang, theme) = if HydrationCtx::is_hydrating() {
+        let (lang, set_lang) = create_signal(prerendered_lang().unwrap_or(Lang::En));
+        let theme = create_rw_signal(Theme::System);
+        request_animation_frame(move || {
+            set_lang.set(initial_lang());
+            theme.set(saved_theme());
+            track_prefers_light(prefers_light);
+        });
+        (lang, set_lang, theme)
+    } else {
+        let (lang, set_lang) = create_signal(initial_lang());
+        track_prefers_light(prefers_light);
+        (lang, set_lang, create_rw_signal(saved_theme()))
+    };
     let (is_sidebar_open, set_sidebar_open) = create_signal(false);
 
-    create_effect(move |_| {
-        setup_keyboard_shortcuts();
-    });
-
-    create_effect(move |_| {
-        if let Some(window) = web_sys::window() {
-            let storage = window.local_storage().ok().flatten();
-            if let Some(storage) = storage {
-                if let Ok(Some(theme_val)) = storage.get_item("theme") {
-                    if theme_val == "light" {
-                        set_theme.set(Theme::Light);
-                    } else {
-                        set_theme.set(Theme::Dark);
-                    }
-                }
-            }
+    let select_theme = move |t: Theme| {
+        theme.set(t);
+        if let Some(storage) = local_storage() {
+            let _ = storage.set_item(THEME_KEY, t.code());
         }
-    });
-
-    let toggle_theme = move |_| {
-        let new_theme = if theme.get() == Theme::Dark {
-            Theme::Light
-        } else {
-            Theme::Dark
-        };
-        set_theme.set(new_theme);
+    };
 
-        if let Some(window) = web_sys::window() {
-            let storage = window.local_storage().ok().flatten();
-            if let Some(storage) = storage {
-                let theme_str = if new_theme == Theme::Dark {
-                    "dark"
-                } else {
-                    "light"
-                };
-                let _ = storage.set_item("theme", theme_str);
-            }
+    let select_lang = move |l: Lang| {
+        set_lang.set(l);
+        if let Some(storage) = local_storage() {
+            let _ = storage.set_item(LANG_KEY, l.code());
         }
     };
 
+    provide_context(PageActions::new());
+    let palette_open = create_rw_signal(false);
+    let _ = window_event_listener(ev::keydown, move |ev| {
+        if (ev.ctrl_key() || ev.meta_key()) && ev.key().eq_ignore_ascii_case("k") {
+            ev.prevent_default();
+            palette_open.update(|open| *open = !*open);
+        }
+    });
+
+    drop_static_meta();
+
     view! {
-        <Title text="工具箱 | Useful Tools"/>
+        <Html lang=move || lang.get().code()/>
 
         <Router base=resolve_router_base() trailing_slash=TrailingSlash::Redirect>
-            <div class=move || format!("layout {}", match theme.get() { Theme::Light => "light", Theme::Dark => "" })>
+            <div class=move || format!("layout theme-{}", theme.get().resolve(prefers_light.get()).code())>
                 <div class="mobile-header">
                     <button class="menu-toggle" aria-label="Toggle menu" on:click=move |_| set_sidebar_open.update(|v| *v = !*v)>
                         "Menu"
@@ -220,73 +1033,56 @@ pub fn App() -> impl IntoView {
                     <div class="sidebar-header">
                         <A href="" class="brand" on:click=move |_| set_sidebar_open.set(false)>"GOD MODE"</A>
                         <div class="header-buttons">
-                            <button class="theme-switch" aria-label="Toggle theme" on:click=toggle_theme>
-                                {move || match theme.get() { Theme::Dark => "☀️", Theme::Light => "🌙" }}
-                            </button>
-                            <button class="lang-switch" aria-label="Toggle language" on:click=move |_| {
-                                set_lang.update(|l| *l = if *l == Lang::En { Lang::Zh } else { Lang::En });
+                            <button class="theme-switch" aria-label="Search tools" title="Ctrl+K" on:click=move |_| palette_open.set(true)>"🔍"</button>
+                            <select class="lang-switch" aria-label="Theme" on:change=move |ev| {
+                                if let Some(t) = Theme::from_code(&event_target_value(&ev)) {
+                                    select_theme(t);
+                                }
+                            }>
+                                {Theme::ALL.into_iter().map(|t| view! {
+                                    <option value=t.code() selected=move || theme.get() == t>{move || lang.get().t(t.name_id())}</option>
+                                }).collect_view()}
+                            </select>
+                            <select class="lang-switch" aria-label="Language" on:change=move |ev| {
+                                if let Some(l) = Lang::from_code(&event_target_value(&ev)) {
+                                    select_lang(l);
+                                }
                             }>
-                                {move || match lang.get() { Lang::En => "中文", Lang::Zh => "EN", }}
-                            </button>
+                                {Lang::ALL.into_iter().map(|l| view! {
+                                    <option value=l.code() selected=move || lang.get() == l>{l.native_name()}</option>
+                                }).collect_view()}
+                            </select>
                         </div>
                     </div>
 
-                    <div class="category">
-                        <div class="category-title">"Encoders"</div>
-                        <A href="base64" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Base64"</A>
-                        <A href="base32" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Base32"</A>
-                        <A href="base58" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Base58"</A>
-                        <A href="html-escape" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"HTML Escape"</A>
-                        <A href="url-escape" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"URL Escape"</A>
-                    </div>
-
-                    <div class="category">
-                        <div class="category-title">"Cryptography"</div>
-                        <A href="hash" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Hash"</A>
-                        <A href="aes" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"AES"</A>
-                        <A href="jwt" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"JWT"</A>
-                    </div>
-
-                    <div class="category">
-                        <div class="category-title">"Development"</div>
-                        <A href="json" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"JSON"</A>
-                        <A href="regex" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Regex"</A>
-                        <A href="diff" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Diff"</A>
-                        <A href="uuid" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"UUID"</A>
-                        <A href="timestamp" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Timestamp"</A>
-                        <A href="base-conv" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Base Conv"</A>
-                    </div>
-
-                    <div class="category">
-                        <div class="category-title">"Utilities"</div>
-                        <A href="cron" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Cron"</A>
-                        <A href="image-base64" class="nav-link" on:click=move |_| set_sidebar_open.set(false)>"Image Base64"</A>
-                    </div>
+                    {Category::ALL.into_iter().map(|category| view! {
+                        <div class="category">
+                            <div class="category-title">{move || lang.get().t(category.title_id())}</div>
+                            {registry::in_category(category).map(|page| view! {
+                                <A href=page.slug class="nav-link" on:click=move |_| set_sidebar_open.set(false)>{page.label}</A>
+                            }).collect_view()}
+                        </div>
+                    }).collect_view()}
                 </nav>
 
                 <div class=move || if is_sidebar_open.get() { "overlay show" } else { "overlay" }
                      on:click=move |_| set_sidebar_open.set(false)></div>
 
+                <CommandPalette
+                    lang=lang
+                    theme=theme
+                    open=palette_open
+                    on_select_theme=Callback::new(select_theme)
+                    on_select_lang=Callback::new(select_lang)
+                />
+
+                <UpdatePrompt lang=lang/>
+
                 <main class="main-content">
-                    <Routes>
-                        <Route path="" view=move || view! { <HomePage lang=lang /> }/>
-                        <Route path="/base64" view=move || view! { <Base64Page lang=lang /> }/>
-                        <Route path="/base32" view=move || view! { <Base32Page lang=lang /> }/>
-                        <Route path="/base58" view=move || view! { <Base58Page lang=lang /> }/>
-                        <Route path="/html-escape" view=move || view! { <HtmlEscapePage lang=lang /> }/>
-                        <Route path="/url-escape" view=move || view! { <UrlEscapePage lang=lang /> }/>
-                        <Route path="/json" view=move || view! { <JsonPage lang=lang /> }/>
-                        <Route path="/hash" view=move || view! { <HashPage lang=lang /> }/>
-                        <Route path="/aes" view=move || view! { <AesPage lang=lang /> }/>
-                        <Route path="/jwt" view=move || view! { <JwtPage lang=lang /> }/>
-                        <Route path="/uuid" view=move || view! { <UuidPage lang=lang /> }/>
-                        <Route path="/regex" view=move || view! { <RegexPage lang=lang /> }/>
-                        <Route path="/timestamp" view=move || view! { <TimestampPage lang=lang /> }/>
-                        <Route path="/base-conv" view=move || view! { <BaseConvPage lang=lang /> }/>
-                        <Route path="/diff" view=move || view! { <DiffPage lang=lang /> }/>
-                        <Route path="/cron" view=move || view! { <CronPage lang=lang /> }/>
-                        <Route path="/image-base64" view=move || view! { <ImageBase64Page lang=lang /> }/>
-                        <Route path="/*" view=move || view! { <HomePage lang=lang /> }/>
+                    <Routes base=resolve_router_base().to_string()>
+                        <Route path="" view=move || view! { <PageMeta lang=lang page=None/> <HomePage lang=lang /> }/>
+                        <Route path="/:tool" view=move || view! { <ToolRoute lang=lang /> }/>
+                        <Route path="/*" view=move || view! { <PageMeta lang=lang page=None/> <HomePage lang=lang /> }/>
                     </Routes>
                 </main>
             </div>
@@ -294,27 +1090,407 @@ pub fn App() -> impl IntoView {
     }
 }
 
+/// Renders the registry page named by the `:tool` segment, or the home page.
+/// Pages read saved preferences and data when they are created, which the
+/// pre-rendered markup cannot have, so a hydrated page is created again once
+/// hydration is over.
+#[component]
+fn ToolRoute(lang: ReadSignal<Lang>) -> impl IntoView {
+    let params = use_params_map();
+    let slug = create_memo(move |_| params.with(|p| p.get("tool").cloned().unwrap_or_default()));
+    let page = Signal::derive(move || registry::find(&slug.get()));
+    let hydrated = create_rw_signal(!HydrationCtx::is_hydrating());
+    if !hydrated.get_untracked() {
+        request_animation_frame(move || hydrated.set(true));
+    }
+    let content = move || {
+        hydrated.track();
+        match page.get().map(|page| page.slug) {
+            Some("base64") => view! { <Base64Page lang=lang /> }.into_view(),
+            Some("base32") => view! { <Base32Page lang=lang /> }.into_view(),
+            Some("base58") => view! { <Base58Page lang=lang /> }.into_view(),
+            Some("ascii85") => view! {
+                <CodecPage
+                    lang=lang
+                    tool=&encoding::Ascii85Tool
+                    label="Ascii85 / Z85"
+                    filename="ascii85.txt"
+                    keys=&["variant"]
+                />
+            }
+            .into_view(),
+            Some("base45") => view! {
+                <CodecPage
+                    lang=lang
+                    tool=&encoding::Base45Tool
+                    label="Base45"
+                    filename="base45.txt"
+                />
+            }
+            .into_view(),
+            Some("base36") => view! {
+                <CodecPage
+                    lang=lang
+                    tool=&encoding::Base36Tool
+                    label="Base36"
+                    filename="base36.txt"
+                />
+            }
+            .into_view(),
+            Some("base62") => view! {
+                <CodecPage
+                    lang=lang
+                    tool=&encoding::Base62Tool
+                    label="Base62"
+                    filename="base62.txt"
+                />
+            }
+            .into_view(),
+            Some("base91") => view! {
+                <CodecPage
+                    lang=lang
+                    tool=&encoding::Base91Tool
+                    label="Base91"
+                    filename="base91.txt"
+                />
+            }
+            .into_view(),
+            Some("html-escape") => view! { <HtmlEscapePage lang=lang /> }.into_view(),
+            Some("url-escape") => view! { <UrlEscapePage lang=lang /> }.into_view(),
+            Some("json") => view! { <JsonPage lang=lang /> }.into_view(),
+            Some("hash") => view! { <HashPage lang=lang /> }.into_view(),
+            Some("aes") => view! { <AesPage lang=lang /> }.into_view(),
+            Some("jwt") => view! { <JwtPage lang=lang /> }.into_view(),
+            Some("uuid") => view! { <UuidPage lang=lang /> }.into_view(),
+            Some("regex") => view! { <RegexPage lang=lang /> }.into_view(),
+            Some("timestamp") => view! { <TimestampPage lang=lang /> }.into_view(),
+            Some("base-conv") => view! { <BaseConvPage lang=lang /> }.into_view(),
+            Some("hexdump") => view! { <HexDumpPage lang=lang /> }.into_view(),
+            Some("diff") => view! { <DiffPage lang=lang /> }.into_view(),
+            Some("cron") => view! { <CronPage lang=lang /> }.into_view(),
+            Some("image-base64") => view! { <ImageBase64Page lang=lang /> }.into_view(),
+            Some("pipeline") => view! { <PipelinePage lang=lang /> }.into_view(),
+            Some("magic") => view! { <MagicPage lang=lang /> }.into_view(),
+            Some("settings") => view! { <SettingsPage lang=lang /> }.into_view(),
+            _ => view! { <HomePage lang=lang /> }.into_view(),
+        }
+    };
+    view! {
+        <PageMeta lang=lang page=page/>
+        {content}
+    }
+}
+
+/// Removes the static copies of the tags `PageMeta` renders, so crawlers that
+/// run the app see one set per page.
+fn drop_static_meta() {
+    let Some(document) = browser_window().and_then(|w| w.document()) else {
+        return;
+    };
+    for (tag, attr) in registry::PAGE_META {
+        while let Ok(Some(element)) = document.query_selector(&format!("{tag}[{attr}]")) {
+            element.remove();
+        }
+    }
+}
+
+/// Localized title, description, keywords, social tags and canonical link of
+/// the current route; `None` is the home page.
+#[component]
+fn PageMeta(
+    lang: ReadSignal<Lang>,
+    #[prop(into)] page: MaybeSignal<Option<&'static registry::ToolPage>>,
+) -> impl IntoView {
+    let title = Signal::derive(move || registry::document_title(page.get(), lang.get()));
+    let description =
+        Signal::derive(move || registry::document_description(page.get(), lang.get()).to_string());
+    let keywords = move || page.get().map(|p| p.keywords.join(",")).unwrap_or_default();
+    let site_url = browser_window()
+        .and_then(|w| w.location().origin().ok())
+        .map(|origin| format!("{origin}{}", resolve_router_base()))
+        .or_else(|| use_context::<PrerenderContext>().map(|host| host.site_url))
+        .unwrap_or_default();
+    let url = Signal::derive(move || registry::page_url(&site_url, page.get()));
+
+    view! {
+        <Title text=move || title.get()/>
+        <Meta name="title" content=move || title.get()/>
+        <Meta name="description" content=move || description.get()/>
+        <Meta name="keywords" content=keywords/>
+        <Meta property="og:title" content=move || title.get()/>
+        <Meta property="og:description" content=move || description.get()/>
+        <Meta property="og:url" content=move || url.get()/>
+        <Meta property="twitter:title" content=move || title.get()/>
+        <Meta property="twitter:description" content=move || description.get()/>
+        <Meta property="twitter:url" content=move || url.get()/>
+        {move || view! { <Link rel="canonical" href=url.get()/> }}
+    }
+}
+
+/// Registers the service worker and offers a reload once a new version has
+/// installed behind the running one.
+#[component]
+fn UpdatePrompt(lang: ReadSignal<Lang>) -> impl IntoView {
+    let waiting = create_rw_signal(None::<web_sys::ServiceWorker>);
+    register_service_worker(waiting);
+
+    let reload = move |_| {
+        if let Some(worker) = waiting.get_untracked() {
+            let _ = worker.post_message(&"skipWaiting".into());
+        }
+    };
+
+    move || {
+        waiting.get().is_some().then(|| view! {
+            <div class="update-banner" role="status">
+                <span>{move || lang.get().t("update.available")}</span>
+                <button class="btn btn-primary" on:click=reload>{move || lang.get().t("update.reload")}</button>
+                <button class="btn btn-secondary" aria-label="Dismiss" on:click=move |_| waiting.set(None)>"✕"</button>
+            </div>
+        })
+    }
+}
+
+/// `sw.js` is resolved against `<base href>`. A worker that finishes
+/// installing while another one controls the page is an update; the page
+/// reloads when the accepted update takes control.
+fn register_service_worker(waiting: RwSignal<Option<web_sys::ServiceWorker>>) {
+    use wasm_bindgen::closure::Closure;
+    use web_sys::{ServiceWorker, ServiceWorkerRegistration, ServiceWorkerState};
+
+    let Some(window) = browser_window() else {
+        return;
+    };
+    let navigator = window.navigator();
+    if !js_sys::Reflect::has(&navigator, &"serviceWorker".into()).unwrap_or(false) {
+        return;
+    }
+    let container = navigator.service_worker();
+    // The first install also claims the page; only a replaced controller reloads.
+    let had_controller = container.controller().is_some();
+
+    let on_controller_change = Closure::<dyn FnMut()>::new(move || {
+        if had_controller {
+            if let Some(window) = browser_window() {
+                let _ = window.location().reload();
+            }
+        }
+    });
+    container.set_oncontrollerchange(Some(on_controller_change.as_ref().unchecked_ref()));
+    on_controller_change.forget();
+
+    spawn_local(async move {
+        let Ok(registration) =
+            wasm_bindgen_futures::JsFuture::from(container.register("sw.js")).await
+        else {
+            return;
+        };
+        let registration: ServiceWorkerRegistration = registration.unchecked_into();
+        if had_controller {
+            if let Some(worker) = registration.waiting() {
+                waiting.set(Some(worker));
+            }
+        }
+
+        let found = registration.clone();
+        let on_update_found = Closure::<dyn FnMut()>::new(move || {
+            let Some(worker) = found.installing() else {
+                return;
+            };
+            let installed: ServiceWorker = worker.clone();
+            let on_state_change = Closure::<dyn FnMut()>::new(move || {
+                if installed.state() == ServiceWorkerState::Installed && had_controller {
+                    waiting.set(Some(installed.clone()));
+                }
+            });
+            worker.set_onstatechange(Some(on_state_change.as_ref().unchecked_ref()));
+            on_state_change.forget();
+        });
+        registration.set_onupdatefound(Some(on_update_found.as_ref().unchecked_ref()));
+        on_update_found.forget();
+    });
+}
+
+// ==================== Command Palette ====================
+#[derive(Clone, Debug, PartialEq)]
+enum Command {
+    Open(String),
+    SetTheme(Theme),
+    SetLang(Lang),
+    Clear,
+    CopyOutput,
+}
+
+#[component]
+fn CommandPalette(
+    lang: ReadSignal<Lang>,
+    theme: RwSignal<Theme>,
+    open: RwSignal<bool>,
+    on_select_theme: Callback<Theme>,
+    on_select_lang: Callback<Lang>,
+) -> impl IntoView {
+    let actions = expect_context::<PageActions>();
+    let navigate = use_navigate();
+    let (query, set_query) = create_signal(String::new());
+    let (selected, set_selected) = create_signal(0usize);
+    let input_ref = create_node_ref::<html::Input>();
+
+    let commands = move || {
+        let lang = lang.get();
+        let mut list = vec![(
+            lang.t("palette.home").to_string(),
+            "home",
+            Command::Open("/".into()),
+        )];
+        list.extend(registry::pages().iter().map(|page| {
+            (
+                page.name(lang).to_string(),
+                page.slug,
+                Command::Open(format!("/{}", page.slug)),
+            )
+        }));
+        let current = theme.get();
+        list.extend(Theme::ALL.into_iter().filter(|&t| t != current).map(|t| {
+            (
+                lang.format("palette.theme", &[("name", lang.t(t.name_id()))]),
+                t.code(),
+                Command::SetTheme(t),
+            )
+        }));
+        list.extend(Lang::ALL.into_iter().filter(|&l| l != lang).map(|l| {
+            (
+                lang.format("palette.language", &[("name", l.native_name())]),
+                l.code(),
+                Command::SetLang(l),
+            )
+        }));
+        if actions.clear.with(Option::is_some) {
+            list.push((lang.t("palette.clear").to_string(), "clear", Command::Clear));
+        }
+        if actions.output.with(Option::is_some) {
+            list.push((
+                lang.t("palette.copy").to_string(),
+                "copy",
+                Command::CopyOutput,
+            ));
+        }
+        list
+    };
+
+    let matches = create_memo(move |_| {
+        let list = commands();
+        let haystacks: Vec<String> = list
+            .iter()
+            .map(|(label, hint, _)| format!("{} {}", label, hint))
+            .collect();
+        query
+            .with(|q| search::fuzzy_filter(q, &haystacks))
+            .into_iter()
+            .map(|i| list[i].clone())
+            .collect::<Vec<_>>()
+    });
+
+    let run = Callback::new(move |command: Command| {
+        open.set(false);
+        match command {
+            Command::Open(path) => navigate(&path, Default::default()),
+            Command::SetTheme(t) => on_select_theme.call(t),
+            Command::SetLang(l) => on_select_lang.call(l),
+            Command::Clear => {
+                if let Some((_, clear)) = actions.clear.get_untracked() {
+                    clear.call(());
+                }
+            }
+            Command::CopyOutput => {
+                if let Some((_, text)) = actions.output.get_untracked() {
+                    copy_to_clipboard(&text.get_untracked());
+                }
+            }
+        }
+    });
+
+    create_effect(move |_| {
+        if open.get() {
+            set_query.set(String::new());
+            set_selected.set(0);
+        }
+    });
+
+    create_effect(move |_| {
+        if let Some(input) = input_ref.get() {
+            let _ = input.focus();
+        }
+    });
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        let count = matches.with(Vec::len);
+        match ev.key().as_str() {
+            "ArrowDown" if count > 0 => {
+                ev.prevent_default();
+                set_selected.update(|i| *i = (*i + 1) % count);
+            }
+            "ArrowUp" if count > 0 => {
+                ev.prevent_default();
+                set_selected.update(|i| *i = (*i + count - 1) % count);
+            }
+            "Enter" => {
+                if let Some((_, _, command)) = matches.with(|m| m.get(selected.get()).cloned()) {
+                    run.call(command);
+                }
+            }
+            "Escape" => open.set(false),
+            _ => {}
+        }
+    };
+
+    view! {
+        <Show when=move || open.get()>
+            <div class="palette-backdrop" on:click=move |_| open.set(false)>
+                <div class="command-palette" role="dialog" aria-label="Command palette" on:click=|ev| ev.stop_propagation()>
+                    <input
+                        type="text"
+                        class="palette-input"
+                        node_ref=input_ref
+                        prop:value=query
+                        on:input=move |ev| {
+                            set_query.set(event_target_value(&ev));
+                            set_selected.set(0);
+                        }
+                        on:keydown=on_keydown
+                        placeholder=move || lang.get().t("palette.placeholder")
+                    />
+                    <div class="palette-list" role="listbox">
+                        {move || matches.get().into_iter().enumerate().map(|(i, (label, hint, command))| view! {
+                            <div
+                                class="palette-item"
+                                class:selected=move || selected.get() == i
+                                role="option"
+                                on:mouseenter=move |_| set_selected.set(i)
+                                on:click=move |_| run.call(command.clone())
+                            >
+                                <span>{label}</span>
+                                <span class="palette-hint">{hint}</span>
+                            </div>
+                        }).collect_view()}
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
 #[component]
 fn HomePage(lang: ReadSignal<Lang>) -> impl IntoView {
     view! {
         <div class="hero">
             <h1>
-                {move || match lang.get() {
-                    Lang::En => "Do Everything Like a God",
-                    Lang::Zh => "做甚麼都有如神助",
-                }}
+                {move || lang.get().t("home.title")}
             </h1>
             <p>
-                {move || match lang.get() {
-                    Lang::En => "Empowering your workflow with divine efficiency.",
-                    Lang::Zh => "賦予你的工作流神一般的效率。",
-                }}
+                {move || lang.get().t("home.tagline")}
             </p>
             <a href="#" class="btn">
-                {move || match lang.get() {
-                    Lang::En => "Get Started",
-                    Lang::Zh => "立即開始",
-                }}
+                {move || lang.get().t("home.start")}
             </a>
         </div>
     }
@@ -323,63 +1499,81 @@ fn HomePage(lang: ReadSignal<Lang>) -> impl IntoView {
 // ==================== Base64 Page ====================
 #[component]
 fn Base64Page(lang: ReadSignal<Lang>) -> impl IntoView {
-    use base64::{engine::general_purpose, Engine as _};
-
-    let (input, set_input) = create_signal(String::new());
+    let tool = &encoding::Base64Tool;
+    let input = create_rw_signal(Vec::<u8>::new());
     let (output, set_output) = create_signal(String::new());
-    let (error, set_error) = create_signal(Option::<String>::None);
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
+    let options = create_rw_signal(load_prefs(tool));
+    let (detected, set_detected) = create_signal(Option::<encoding::Base64Variant>::None);
 
     let encode = move |_| {
         set_error.set(None);
-        let encoded = general_purpose::STANDARD.encode(input.get().as_bytes());
-        set_output.set(encoded);
+        set_detected.set(None);
+        match options.with(encoding::Base64Variant::from_options) {
+            Ok(variant) => set_output.set(encoding::base64_encode_with(&input.get(), &variant)),
+            Err(e) => set_error.set(Some(e)),
+        }
     };
 
     let decode = move |_| {
         set_error.set(None);
-        match general_purpose::STANDARD.decode(output.get().trim()) {
-            Ok(bytes) => match String::from_utf8(bytes) {
-                Ok(s) => set_input.set(s),
-                Err(e) => set_error.set(Some(format!("UTF-8 Error: {}", e))),
-            },
-            Err(e) => set_error.set(Some(format!("Decode Error: {}", e))),
+        set_detected.set(None);
+        let result = options.with(|o| match o.value(&encoding::BASE64_DECODER)? {
+            "strict" => encoding::base64_decode_strict(
+                &output.get(),
+                &encoding::Base64Variant::from_options(o)?,
+            )
+            .map(|b| (b, None)),
+            _ => encoding::base64_decode_lenient(&output.get()).map(|(b, v)| (b, Some(v))),
+        });
+        match result {
+            Ok((b, variant)) => {
+                input.set(b);
+                set_detected.set(variant);
+            }
+            Err(e) => set_error.set(Some(e)),
         }
     };
 
+    let mut state = byte_state("input", "input-hex", input).to_vec();
+    state.push(state_field("output", output, set_output));
+
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Base64" title_zh="Base64 工具"/>
+            <ToolHeader lang=lang tool=tool state=state/>
+            <div class="box">
+                <div class="pipeline-options">
+                    <ToolOptions lang=lang tool=tool options=options keys=&["alphabet", "padding", "wrap", "decoder"]/>
+                </div>
+            </div>
             <div class="tool-grid">
                 <div class="box">
                     <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Text", Lang::Zh => "文字", }}</div>
-                        <ClearButton on_click=move || { set_input.set(String::new()); set_output.set(String::new()); }/>
+                        <div class="box-label">{move || lang.get().t("common.input")}</div>
+                        <ClearButton on_click=move || { input.set(Vec::new()); set_output.set(String::new()); set_detected.set(None); }/>
                     </div>
-                    <textarea
-                        prop:value=input
-                        on:input=move |ev| set_input.set(event_target_value(&ev))
-                        placeholder="..."
-                    ></textarea>
+                    <ByteField lang=lang bytes=input/>
                     <div class="btn-row">
-                        <button class="btn" on:click=encode>{move || match lang.get() { Lang::En => "Encode →", Lang::Zh => "編碼 →", }}</button>
+                        <button class="btn" on:click=encode>{move || lang.get().t("common.encode")}</button>
                     </div>
                 </div>
                 <div class="box">
                     <div class="box-header">
                         <div class="box-label">"Base64"</div>
-                        <CopyButton text=output/>
+                        <div class="header-buttons"><CopyButton text=output primary=true/><DownloadButton text=output filename="base64.txt"/></div>
                     </div>
-                    <textarea
-                        prop:value=output
-                        on:input=move |ev| set_output.set(event_target_value(&ev))
-                        placeholder="..."
-                    ></textarea>
+                    <TextInput lang=lang value=output on_input=move |v| set_output.set(v)/>
                     <div class="btn-row">
-                        <button class="btn" on:click=decode>{move || match lang.get() { Lang::En => "← Decode", Lang::Zh => "← 解碼", }}</button>
+                        <button class="btn" on:click=decode>{move || lang.get().t("common.decode")}</button>
                     </div>
+                    {move || detected.get().map(|variant| view! {
+                        <div class="hint">
+                            {lang.get().format("base64.detected", &[("variant", &variant.describe(lang.get()))])}
+                        </div>
+                    })}
                 </div>
             </div>
-            {move || error.get().map(|e| view! { <div class="error">{e}</div> })}
+            {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
         </div>
     }
 }
@@ -387,53 +1581,65 @@ fn Base64Page(lang: ReadSignal<Lang>) -> impl IntoView {
 // ==================== Base32 Page ====================
 #[component]
 fn Base32Page(lang: ReadSignal<Lang>) -> impl IntoView {
-    let (input, set_input) = create_signal(String::new());
+    let tool = &encoding::Base32Tool;
+    let input = create_rw_signal(Vec::<u8>::new());
     let (output, set_output) = create_signal(String::new());
-    let (error, set_error) = create_signal(Option::<String>::None);
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
+    let options = create_rw_signal(load_prefs(tool));
 
     let encode = move |_| {
         set_error.set(None);
-        let encoded = base32::encode(base32::Alphabet::Crockford, input.get().as_bytes());
-        set_output.set(encoded);
+        match options.with(encoding::Base32Alphabet::from_options) {
+            Ok(alphabet) => set_output.set(encoding::base32_encode_with(&input.get(), alphabet)),
+            Err(e) => set_error.set(Some(e)),
+        }
     };
 
     let decode = move |_| {
         set_error.set(None);
-        match base32::decode(base32::Alphabet::Crockford, output.get().trim()) {
-            Some(bytes) => match String::from_utf8(bytes) {
-                Ok(s) => set_input.set(s),
-                Err(e) => set_error.set(Some(format!("UTF-8 Error: {}", e))),
-            },
-            None => set_error.set(Some("Invalid Base32".to_string())),
+        let result = options
+            .with(encoding::Base32Alphabet::from_options)
+            .and_then(|alphabet| encoding::base32_decode_with(&output.get(), alphabet));
+        match result {
+            Ok(b) => input.set(b),
+            Err(e) => set_error.set(Some(e)),
         }
     };
 
+    let mut state = byte_state("input", "input-hex", input).to_vec();
+    state.push(state_field("output", output, set_output));
+
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Base32" title_zh="Base32 工具"/>
+            <ToolHeader lang=lang tool=tool state=state/>
+            <div class="box">
+                <div class="pipeline-options">
+                    <ToolOptions lang=lang tool=tool options=options keys=&["alphabet"]/>
+                </div>
+            </div>
             <div class="tool-grid">
                 <div class="box">
                     <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Text", Lang::Zh => "文字", }}</div>
-                        <ClearButton on_click=move || { set_input.set(String::new()); set_output.set(String::new()); }/>
+                        <div class="box-label">{move || lang.get().t("common.input")}</div>
+                        <ClearButton on_click=move || { input.set(Vec::new()); set_output.set(String::new()); }/>
                     </div>
-                    <textarea prop:value=input on:input=move |ev| set_input.set(event_target_value(&ev)) placeholder="..."></textarea>
+                    <ByteField lang=lang bytes=input/>
                     <div class="btn-row">
-                        <button class="btn" on:click=encode>{move || match lang.get() { Lang::En => "Encode →", Lang::Zh => "編碼 →", }}</button>
+                        <button class="btn" on:click=encode>{move || lang.get().t("common.encode")}</button>
                     </div>
                 </div>
                 <div class="box">
                     <div class="box-header">
                         <div class="box-label">"Base32"</div>
-                        <CopyButton text=output/>
+                        <div class="header-buttons"><CopyButton text=output primary=true/><DownloadButton text=output filename="base32.txt"/></div>
                     </div>
-                    <textarea prop:value=output on:input=move |ev| set_output.set(event_target_value(&ev)) placeholder="..."></textarea>
+                    <TextInput lang=lang value=output on_input=move |v| set_output.set(v)/>
                     <div class="btn-row">
-                        <button class="btn" on:click=decode>{move || match lang.get() { Lang::En => "← Decode", Lang::Zh => "← 解碼", }}</button>
+                        <button class="btn" on:click=decode>{move || lang.get().t("common.decode")}</button>
                     </div>
                 </div>
             </div>
-            {move || error.get().map(|e| view! { <div class="error">{e}</div> })}
+            {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
         </div>
     }
 }
@@ -441,53 +1647,190 @@ fn Base32Page(lang: ReadSignal<Lang>) -> impl IntoView {
 // ==================== Base58 Page ====================
 #[component]
 fn Base58Page(lang: ReadSignal<Lang>) -> impl IntoView {
-    let (input, set_input) = create_signal(String::new());
+    let tool = &encoding::Base58Tool;
+    let input = create_rw_signal(Vec::<u8>::new());
     let (output, set_output) = create_signal(String::new());
-    let (error, set_error) = create_signal(Option::<String>::None);
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
+    let options = create_rw_signal(load_prefs(tool));
+    // Base58Check's version byte, as hex; empty for none.
+    let (version, set_version) = create_signal(String::new());
+    let (verified, set_verified) = create_signal(Option::<Option<u8>>::None);
+
+    let checked =
+        move || options.with(|o| o.value(&encoding::BASE58_CHECKSUM) == Ok("base58check"));
+
+    let version_byte = move || match bytes::parse_hex(&version.get()).as_deref() {
+        Ok([]) => Ok(None),
+        Ok([byte]) => Ok(Some(*byte)),
+        _ => Err(ToolError::InvalidOption {
+            key: "version".into(),
+            value: version.get(),
+        }),
+    };
 
     let encode = move |_| {
         set_error.set(None);
-        let encoded = bs58::encode(input.get().as_bytes()).into_string();
-        set_output.set(encoded);
+        set_verified.set(None);
+        let result = options
+            .with(encoding::Base58Alphabet::from_options)
+            .and_then(|alphabet| {
+                if !checked() {
+                    return Ok(encoding::base58_encode_with(&input.get(), alphabet));
+                }
+                let mut data: Vec<u8> = version_byte()?.into_iter().collect();
+                data.extend(input.get());
+                Ok(encoding::base58check_encode(&data, alphabet))
+            });
+        match result {
+            Ok(encoded) => set_output.set(encoded),
+            Err(e) => set_error.set(Some(e)),
+        }
     };
 
     let decode = move |_| {
         set_error.set(None);
-        match bs58::decode(output.get().trim()).into_vec() {
-            Ok(bytes) => match String::from_utf8(bytes) {
-                Ok(s) => set_input.set(s),
-                Err(e) => set_error.set(Some(format!("UTF-8 Error: {}", e))),
-            },
-            Err(e) => set_error.set(Some(format!("Decode Error: {}", e))),
+        set_verified.set(None);
+        let result = options
+            .with(encoding::Base58Alphabet::from_options)
+            .and_then(|alphabet| {
+                if checked() {
+                    encoding::base58check_decode(&output.get(), alphabet).map(|mut data| {
+                        let version = (!data.is_empty()).then(|| data.remove(0));
+                        (data, Some(version))
+                    })
+                } else {
+                    encoding::base58_decode_with(&output.get(), alphabet).map(|data| (data, None))
+                }
+            });
+        match result {
+            Ok((data, checked_version)) => {
+                if let Some(v) = checked_version {
+                    set_version.set(v.map(|v| format!("{v:02x}")).unwrap_or_default());
+                }
+                set_verified.set(checked_version);
+                input.set(data);
+            }
+            Err(e) => set_error.set(Some(e)),
         }
     };
 
+    let mut state = byte_state("input", "input-hex", input).to_vec();
+    state.push(state_field("output", output, set_output));
+    state.push(state_field("version", version, set_version));
+
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Base58" title_zh="Base58 工具"/>
+            <ToolHeader lang=lang tool=tool state=state/>
+            <div class="box">
+                <div class="pipeline-options">
+                    <ToolOptions lang=lang tool=tool options=options keys=&["alphabet", "checksum"]/>
+                    {move || checked().then(|| view! {
+                        <label class="pipeline-option">
+                            <span>{move || lang.get().t("base58.version")}</span>
+                            <input type="text" class="version-input" prop:value=version on:input=move |ev| set_version.set(event_target_value(&ev)) placeholder="00"/>
+                        </label>
+                    })}
+                </div>
+            </div>
             <div class="tool-grid">
                 <div class="box">
                     <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Text", Lang::Zh => "文字", }}</div>
-                        <ClearButton on_click=move || { set_input.set(String::new()); set_output.set(String::new()); }/>
+                        <div class="box-label">{move || lang.get().t("common.input")}</div>
+                        <ClearButton on_click=move || { input.set(Vec::new()); set_output.set(String::new()); set_verified.set(None); }/>
                     </div>
-                    <textarea prop:value=input on:input=move |ev| set_input.set(event_target_value(&ev)) placeholder="..."></textarea>
+                    <ByteField lang=lang bytes=input/>
                     <div class="btn-row">
-                        <button class="btn" on:click=encode>{move || match lang.get() { Lang::En => "Encode →", Lang::Zh => "編碼 →", }}</button>
+                        <button class="btn" on:click=encode>{move || lang.get().t("common.encode")}</button>
                     </div>
                 </div>
                 <div class="box">
                     <div class="box-header">
                         <div class="box-label">"Base58"</div>
-                        <CopyButton text=output/>
+                        <div class="header-buttons"><CopyButton text=output primary=true/><DownloadButton text=output filename="base58.txt"/></div>
                     </div>
-                    <textarea prop:value=output on:input=move |ev| set_output.set(event_target_value(&ev)) placeholder="..."></textarea>
+                    <TextInput lang=lang value=output on_input=move |v| set_output.set(v)/>
                     <div class="btn-row">
-                        <button class="btn" on:click=decode>{move || match lang.get() { Lang::En => "← Decode", Lang::Zh => "← 解碼", }}</button>
+                        <button class="btn" on:click=decode>{move || lang.get().t("common.decode")}</button>
                     </div>
+                    {move || verified.get().map(|version| view! {
+                        <div class="hint">
+                            {match version {
+                                Some(v) => lang.get().format("base58.verified", &[("version", &format!("0x{v:02x} ({v})"))]),
+                                None => lang.get().t("base58.verified-empty").to_string(),
+                            }}
+                        </div>
+                    })}
                 </div>
             </div>
-            {move || error.get().map(|e| view! { <div class="error">{e}</div> })}
+            {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
+        </div>
+    }
+}
+
+// ==================== Codec Page ====================
+/// Base64Page's layout for encoders that only need `Tool::run`: bytes on the
+/// left, text on the right, and the `keys` options above.
+#[component]
+fn CodecPage(
+    lang: ReadSignal<Lang>,
+    tool: &'static dyn Tool,
+    label: &'static str,
+    filename: &'static str,
+    #[prop(optional)] keys: &'static [&'static str],
+) -> impl IntoView {
+    let input = create_rw_signal(Vec::<u8>::new());
+    let (output, set_output) = create_signal(String::new());
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
+    let options = create_rw_signal(load_prefs(tool));
+
+    let run = move |data: &[u8], mode: &str| {
+        set_error.set(None);
+        let result = tool.run(data, &options.get().with("mode", mode));
+        match result {
+            Ok(tools::Output::Bytes(b)) => input.set(b),
+            Ok(out) => set_output.set(out.to_text()),
+            Err(e) => set_error.set(Some(e)),
+        }
+    };
+    let encode = move |_| run(&input.get(), "encode");
+    let decode = move |_| run(output.get().as_bytes(), "decode");
+
+    let mut state = byte_state("input", "input-hex", input).to_vec();
+    state.push(state_field("output", output, set_output));
+
+    view! {
+        <div class="tool-container">
+            <ToolHeader lang=lang tool=tool state=state/>
+            {(!keys.is_empty()).then(|| view! {
+                <div class="box">
+                    <div class="pipeline-options">
+                        <ToolOptions lang=lang tool=tool options=options keys=keys/>
+                    </div>
+                </div>
+            })}
+            <div class="tool-grid">
+                <div class="box">
+                    <div class="box-header">
+                        <div class="box-label">{move || lang.get().t("common.input")}</div>
+                        <ClearButton on_click=move || { input.set(Vec::new()); set_output.set(String::new()); }/>
+                    </div>
+                    <ByteField lang=lang bytes=input/>
+                    <div class="btn-row">
+                        <button class="btn" on:click=encode>{move || lang.get().t("common.encode")}</button>
+                    </div>
+                </div>
+                <div class="box">
+                    <div class="box-header">
+                        <div class="box-label">{label}</div>
+                        <div class="header-buttons"><CopyButton text=output primary=true/><DownloadButton text=output filename=filename/></div>
+                    </div>
+                    <TextInput lang=lang value=output on_input=move |v| set_output.set(v)/>
+                    <div class="btn-row">
+                        <button class="btn" on:click=decode>{move || lang.get().t("common.decode")}</button>
+                    </div>
+                </div>
+            </div>
+            {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
         </div>
     }
 }
@@ -499,37 +1842,35 @@ fn HtmlEscapePage(lang: ReadSignal<Lang>) -> impl IntoView {
     let (output, set_output) = create_signal(String::new());
 
     let escape = move |_| {
-        let escaped = html_escape::encode_safe(&input.get()).to_string();
-        set_output.set(escaped);
+        set_output.set(encoding::html_escape(&input.get()));
     };
 
     let unescape = move |_| {
-        let unescaped = html_escape::decode_html_entities(&output.get()).to_string();
-        set_input.set(unescaped);
+        set_input.set(encoding::html_unescape(&output.get()));
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="HTML Escape" title_zh="HTML 轉義"/>
+            <ToolHeader lang=lang tool=&encoding::HtmlEscapeTool state=vec![state_field("input", input, set_input), state_field("output", output, set_output)]/>
             <div class="tool-grid">
                 <div class="box">
                     <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Raw HTML", Lang::Zh => "原始 HTML", }}</div>
+                        <div class="box-label">{move || lang.get().t("html.raw")}</div>
                         <ClearButton on_click=move || { set_input.set(String::new()); set_output.set(String::new()); }/>
                     </div>
-                    <textarea prop:value=input on:input=move |ev| set_input.set(event_target_value(&ev)) placeholder="<div>...</div>"></textarea>
+                    <TextInput lang=lang value=input on_input=move |v| set_input.set(v) placeholder="<div>...</div>"/>
                     <div class="btn-row">
-                        <button class="btn" on:click=escape>{move || match lang.get() { Lang::En => "Escape →", Lang::Zh => "轉義 →", }}</button>
+                        <button class="btn" on:click=escape>{move || lang.get().t("html.escape")}</button>
                     </div>
                 </div>
                 <div class="box">
                     <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Escaped", Lang::Zh => "轉義結果", }}</div>
-                        <CopyButton text=output/>
+                        <div class="box-label">{move || lang.get().t("html.escaped")}</div>
+                        <div class="header-buttons"><CopyButton text=output primary=true/><DownloadButton text=output filename="escaped.html"/></div>
                     </div>
-                    <textarea prop:value=output on:input=move |ev| set_output.set(event_target_value(&ev)) placeholder="&lt;div&gt;..."></textarea>
+                    <TextInput lang=lang value=output on_input=move |v| set_output.set(v) placeholder="&lt;div&gt;..."/>
                     <div class="btn-row">
-                        <button class="btn" on:click=unescape>{move || match lang.get() { Lang::En => "← Unescape", Lang::Zh => "← 還原", }}</button>
+                        <button class="btn" on:click=unescape>{move || lang.get().t("html.unescape")}</button>
                     </div>
                 </div>
             </div>
@@ -544,38 +1885,37 @@ fn UrlEscapePage(lang: ReadSignal<Lang>) -> impl IntoView {
     let (output, set_output) = create_signal(String::new());
 
     let encode = move |_| {
-        let encoded = urlencoding::encode(&input.get()).to_string();
-        set_output.set(encoded);
+        set_output.set(encoding::url_encode(&input.get()));
     };
 
     let decode = move |_| {
-        if let Ok(decoded) = urlencoding::decode(&output.get()) {
-            set_input.set(decoded.into_owned());
+        if let Ok(decoded) = encoding::url_decode(&output.get()) {
+            set_input.set(decoded);
         }
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="URL Encode" title_zh="URL 編碼"/>
+            <ToolHeader lang=lang tool=&encoding::UrlEscapeTool state=vec![state_field("input", input, set_input), state_field("output", output, set_output)]/>
             <div class="tool-grid">
                 <div class="box">
                     <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Raw URL", Lang::Zh => "原始 URL", }}</div>
+                        <div class="box-label">{move || lang.get().t("url.raw")}</div>
                         <ClearButton on_click=move || { set_input.set(String::new()); set_output.set(String::new()); }/>
                     </div>
-                    <textarea prop:value=input on:input=move |ev| set_input.set(event_target_value(&ev)) placeholder="https://example.com/測試"></textarea>
+                    <TextInput lang=lang value=input on_input=move |v| set_input.set(v) placeholder="https://example.com/測試"/>
                     <div class="btn-row">
-                        <button class="btn" on:click=encode>{move || match lang.get() { Lang::En => "Encode →", Lang::Zh => "編碼 →", }}</button>
+                        <button class="btn" on:click=encode>{move || lang.get().t("common.encode")}</button>
                     </div>
                 </div>
                 <div class="box">
                     <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Encoded", Lang::Zh => "編碼結果", }}</div>
-                        <CopyButton text=output/>
+                        <div class="box-label">{move || lang.get().t("common.encoded")}</div>
+                        <div class="header-buttons"><CopyButton text=output primary=true/><DownloadButton text=output filename="encoded.txt"/></div>
                     </div>
-                    <textarea prop:value=output on:input=move |ev| set_output.set(event_target_value(&ev)) placeholder="https%3A%2F%2F..."></textarea>
+                    <TextInput lang=lang value=output on_input=move |v| set_output.set(v) placeholder="https%3A%2F%2F..."/>
                     <div class="btn-row">
-                        <button class="btn" on:click=decode>{move || match lang.get() { Lang::En => "← Decode", Lang::Zh => "← 解碼", }}</button>
+                        <button class="btn" on:click=decode>{move || lang.get().t("common.decode")}</button>
                     </div>
                 </div>
             </div>
@@ -588,45 +1928,38 @@ fn UrlEscapePage(lang: ReadSignal<Lang>) -> impl IntoView {
 fn JsonPage(lang: ReadSignal<Lang>) -> impl IntoView {
     let (input, set_input) = create_signal(String::new());
     let (output, set_output) = create_signal(String::new());
-    let (error, set_error) = create_signal(Option::<String>::None);
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
 
     let process = move |minify: bool| {
         set_error.set(None);
-        match serde_json::from_str::<serde_json::Value>(&input.get()) {
-            Ok(v) => {
-                let res = if minify {
-                    serde_json::to_string(&v).unwrap()
-                } else {
-                    serde_json::to_string_pretty(&v).unwrap()
-                };
-                set_output.set(res);
-            }
-            Err(e) => set_error.set(Some(e.to_string())),
+        match development::json_format(&input.get(), minify) {
+            Ok(res) => set_output.set(res),
+            Err(e) => set_error.set(Some(e)),
         }
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="JSON Tool" title_zh="JSON 工具"/>
+            <ToolHeader lang=lang tool=&development::JsonTool state=vec![state_field("input", input, set_input), state_field("output", output, set_output)]/>
             <div class="tool-grid">
                 <div class="box">
                     <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Input", Lang::Zh => "輸入", }}</div>
+                        <div class="box-label">{move || lang.get().t("common.input")}</div>
                         <ClearButton on_click=move || { set_input.set(String::new()); set_output.set(String::new()); set_error.set(None); }/>
                     </div>
-                    <textarea prop:value=input on:input=move |ev| set_input.set(event_target_value(&ev)) placeholder=r#"{"key":"value"}"#></textarea>
+                    <TextInput lang=lang value=input on_input=move |v| set_input.set(v) placeholder=r#"{"key":"value"}"#/>
                     <div class="btn-row">
-                        <button class="btn" on:click=move |_| process(false)>{move || match lang.get() { Lang::En => "Prettify", Lang::Zh => "格式化", }}</button>
-                        <button class="btn" on:click=move |_| process(true)>{move || match lang.get() { Lang::En => "Minify", Lang::Zh => "壓縮", }}</button>
+                        <button class="btn" on:click=move |_| process(false)>{move || lang.get().t("json.prettify")}</button>
+                        <button class="btn" on:click=move |_| process(true)>{move || lang.get().t("json.minify")}</button>
                     </div>
                 </div>
                 <div class="box">
                     <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Result", Lang::Zh => "結果", }}</div>
-                        <CopyButton text=output/>
+                        <div class="box-label">{move || lang.get().t("common.result")}</div>
+                        <div class="header-buttons"><CopyButton text=output primary=true/><DownloadButton text=output filename="formatted.json"/></div>
                     </div>
                     <textarea prop:value=output readonly placeholder="..."></textarea>
-                    {move || error.get().map(|e| view! { <div class="error">{e}</div> })}
+                    {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
                 </div>
             </div>
         </div>
@@ -635,10 +1968,7 @@ fn JsonPage(lang: ReadSignal<Lang>) -> impl IntoView {
 // ==================== Hash Page ====================
 #[component]
 fn HashPage(lang: ReadSignal<Lang>) -> impl IntoView {
-    use md5::Md5;
-    use sha1::Sha1;
-    use sha2::{Digest, Sha256, Sha512};
-    use sha3::Sha3_256;
+    use crypto::HashAlgorithm;
 
     let (input, set_input) = create_signal(String::new());
     let (md5_res, set_md5) = create_signal(String::new());
@@ -646,33 +1976,31 @@ fn HashPage(lang: ReadSignal<Lang>) -> impl IntoView {
     let (sha256_res, set_sha256) = create_signal(String::new());
     let (sha512_res, set_sha512) = create_signal(String::new());
     let (sha3_res, set_sha3) = create_signal(String::new());
-    let (is_loading, set_loading) = create_signal(false);
-
-    let compute_hashes = move |data: &[u8]| {
-        let mut md5_hasher = Md5::new();
-        md5_hasher.update(data);
-        set_md5.set(hex::encode(md5_hasher.finalize()));
-
-        let mut sha1_hasher = Sha1::new();
-        sha1_hasher.update(data);
-        set_sha1.set(hex::encode(sha1_hasher.finalize()));
-
-        let mut sha256_hasher = Sha256::new();
-        sha256_hasher.update(data);
-        set_sha256.set(hex::encode(sha256_hasher.finalize()));
-
-        let mut sha512_hasher = Sha512::new();
-        sha512_hasher.update(data);
-        set_sha512.set(hex::encode(sha512_hasher.finalize()));
-
-        let mut sha3_hasher = Sha3_256::new();
-        sha3_hasher.update(data);
-        set_sha3.set(hex::encode(sha3_hasher.finalize()));
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
+    let runner = JobRunner::new();
+
+    let show_hashes = move |result: Result<JobOutput, ToolError>| match result {
+        Ok(JobOutput::Hashes(digests)) => {
+            set_error.set(None);
+            for (alg, digest) in digests {
+                match alg {
+                    HashAlgorithm::Md5 => set_md5.set(digest),
+                    HashAlgorithm::Sha1 => set_sha1.set(digest),
+                    HashAlgorithm::Sha256 => set_sha256.set(digest),
+                    HashAlgorithm::Sha512 => set_sha512.set(digest),
+                    HashAlgorithm::Sha3_256 => set_sha3.set(digest),
+                }
+            }
+        }
+        Ok(_) => {}
+        Err(e) => set_error.set(Some(e)),
     };
+    let compute_hashes = move |data: &[u8]| runner.submit(Job::Hash, data, show_hashes);
 
     let on_text_input = move |val: String| {
         set_input.set(val.clone());
         if val.is_empty() {
+            runner.cancel();
             set_md5.set(String::new());
             set_sha1.set(String::new());
             set_sha256.set(String::new());
@@ -683,50 +2011,52 @@ fn HashPage(lang: ReadSignal<Lang>) -> impl IntoView {
         compute_hashes(val.as_bytes());
     };
 
+    let hashes = Signal::derive(move || {
+        [md5_res, sha1_res, sha256_res, sha512_res, sha3_res]
+            .iter()
+            .map(|digest| digest.get())
+            .filter(|digest| !digest.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+
+    // The text no longer matches the digests, so it is not kept for
+    // permalinks or history.
+    let hash_file = move |file: web_sys::File| {
+        set_input.set(String::new());
+        runner.submit_blob(Job::Hash, file.into(), show_hashes);
+    };
     let on_file_change = move |ev: ev::Event| {
         let target = event_target::<web_sys::HtmlInputElement>(&ev);
-        if let Some(files) = target.files() {
-            if let Some(file) = files.get(0) {
-                set_loading.set(true);
-                let reader = web_sys::FileReader::new().unwrap();
-                let reader_c = reader.clone();
-                let onload =
-                    wasm_bindgen::closure::Closure::wrap(Box::new(move |_e: web_sys::Event| {
-                        let array_buffer = reader_c.result().unwrap();
-                        let uint8_array = js_sys::Uint8Array::new(&array_buffer);
-                        let bytes = uint8_array.to_vec();
-                        compute_hashes(&bytes);
-                        set_loading.set(false);
-                    })
-                        as Box<dyn FnMut(_)>);
-                reader.set_onload(Some(onload.as_ref().unchecked_ref()));
-                reader.read_as_array_buffer(&file).unwrap();
-                onload.forget();
-            }
+        if let Some(file) = target.files().and_then(|files| files.get(0)) {
+            hash_file(file);
         }
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Hash" title_zh="Hash 工具"/>
-            <div class="tool-grid">
-                <div class="box">
-                    <div class="box-header">
-                        <div class="box-label">{move || match lang.get() { Lang::En => "Input", Lang::Zh => "輸入", }}</div>
-                        <ClearButton on_click=move || { set_input.set(String::new()); set_md5.set(String::new()); set_sha1.set(String::new()); set_sha256.set(String::new()); set_sha512.set(String::new()); set_sha3.set(String::new()); }/>
+            <ToolHeader lang=lang tool=&crypto::HashTool state=vec![state_field("input", input, SignalSetter::map(on_text_input))] output=hashes/>
+            <FileDrop on_file=hash_file>
+                <div class="tool-grid">
+                    <div class="box">
+                        <div class="box-header">
+                            <div class="box-label">{move || lang.get().t("common.input")}</div>
+                            <ClearButton on_click=move || { runner.cancel(); set_input.set(String::new()); set_md5.set(String::new()); set_sha1.set(String::new()); set_sha256.set(String::new()); set_sha512.set(String::new()); set_sha3.set(String::new()); }/>
+                        </div>
+                        <textarea prop:value=input on:input=move |ev| on_text_input(event_target_value(&ev)) placeholder="..."></textarea>
+                    </div>
+                    <div class="box">
+                        <div class="box-label">{move || lang.get().t("common.file-upload")}</div>
+                        <input type="file" on:change=on_file_change class="file-input"/>
+                        <JobStatus lang=lang runner=runner/>
+                        {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
                     </div>
-                    <textarea prop:value=input on:input=move |ev| on_text_input(event_target_value(&ev)) placeholder="..."></textarea>
-                </div>
-                <div class="box">
-                    <div class="box-label">{move || match lang.get() { Lang::En => "File Upload", Lang::Zh => "上傳檔案", }}</div>
-                    <input type="file" on:change=on_file_change class="file-input"/>
-                    {move || if is_loading.get() { view! { <div class="loading">"..."</div> } } else { view! { <div></div> } }}
                 </div>
-            </div>
+            </FileDrop>
             <div class="hash-results">
                 <div class="box"><div class="box-header"><div class="box-label">"MD5"</div><CopyButton text=md5_res/></div><input type="text" prop:value=md5_res readonly class="hash-output"/></div>
                 <div class="box"><div class="box-header"><div class="box-label">"SHA1"</div><CopyButton text=sha1_res/></div><input type="text" prop:value=sha1_res readonly class="hash-output"/></div>
-                <div class="box"><div class="box-header"><div class="box-label">"SHA256"</div><CopyButton text=sha256_res/></div><input type="text" prop:value=sha256_res readonly class="hash-output"/></div>
+                <div class="box"><div class="box-header"><div class="box-label">"SHA256"</div><CopyButton text=sha256_res primary=true/></div><input type="text" prop:value=sha256_res readonly class="hash-output"/></div>
                 <div class="box"><div class="box-header"><div class="box-label">"SHA512"</div><CopyButton text=sha512_res/></div><input type="text" prop:value=sha512_res readonly class="hash-output"/></div>
                 <div class="box"><div class="box-header"><div class="box-label">"SHA3-256"</div><CopyButton text=sha3_res/></div><input type="text" prop:value=sha3_res readonly class="hash-output"/></div>
             </div>
@@ -737,89 +2067,58 @@ fn HashPage(lang: ReadSignal<Lang>) -> impl IntoView {
 // ==================== AES Page ====================
 #[component]
 fn AesPage(lang: ReadSignal<Lang>) -> impl IntoView {
-    use aes_gcm::{aead::Aead, Aes256Gcm, KeyInit, Nonce};
-    use rand::Rng;
-
     let (input, set_input) = create_signal(String::new());
     let (output, set_output) = create_signal(String::new());
     let (key, set_key) = create_signal(String::new());
-    let (error, set_error) = create_signal(Option::<String>::None);
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
 
     let generate_key = move |_| {
-        let random_key: [u8; 32] = rand::thread_rng().gen();
-        set_key.set(hex::encode(random_key));
+        set_key.set(crypto::aes_generate_key());
     };
 
     let encrypt = move |_| {
         set_error.set(None);
-        let key_bytes = match hex::decode(key.get().trim()) {
-            Ok(b) if b.len() == 32 => b,
-            _ => {
-                set_error.set(Some("Key must be 32 bytes (64 hex chars)".into()));
-                return;
-            }
-        };
-        let cipher = Aes256Gcm::new_from_slice(&key_bytes).unwrap();
-        let nonce = Nonce::from_slice(b"unique nonce");
-        match cipher.encrypt(nonce, input.get().as_bytes()) {
-            Ok(encrypted) => set_output.set(hex::encode(encrypted)),
-            Err(e) => set_error.set(Some(e.to_string())),
+        match crypto::aes_encrypt(&key.get(), input.get().as_bytes()) {
+            Ok(encrypted) => set_output.set(encrypted),
+            Err(e) => set_error.set(Some(e)),
         }
     };
 
     let decrypt = move |_| {
         set_error.set(None);
-        let key_bytes = match hex::decode(key.get().trim()) {
-            Ok(b) if b.len() == 32 => b,
-            _ => {
-                set_error.set(Some("Key must be 32 bytes (64 hex chars)".into()));
-                return;
-            }
-        };
-        let encrypted = match hex::decode(output.get().trim()) {
-            Ok(b) => b,
-            Err(e) => {
-                set_error.set(Some(format!("Invalid hex: {}", e)));
-                return;
-            }
-        };
-        let cipher = Aes256Gcm::new_from_slice(&key_bytes).unwrap();
-        let nonce = Nonce::from_slice(b"unique nonce");
-        match cipher.decrypt(nonce, encrypted.as_slice()) {
-            Ok(decrypted) => match String::from_utf8(decrypted) {
-                Ok(s) => set_input.set(s),
-                Err(e) => set_error.set(Some(format!("UTF-8 Error: {}", e))),
-            },
-            Err(e) => set_error.set(Some(e.to_string())),
+        match crypto::aes_decrypt(&key.get(), &output.get()).and_then(|b| Ok(String::from_utf8(b)?))
+        {
+            Ok(s) => set_input.set(s),
+            Err(e) => set_error.set(Some(e)),
         }
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="AES" title_zh="AES 加密"/>
+            <ToolHeader lang=lang tool=&crypto::AesTool state=vec![state_field("input", input, set_input), state_field("output", output, set_output)]/>
             <div class="box" style="margin-bottom:20px">
                 <div class="box-header">
-                    <div class="box-label">{move || match lang.get() { Lang::En => "Key (64 hex chars)", Lang::Zh => "密鑰 (64位十六進制)", }}</div>
+                    <div class="box-label">{move || lang.get().t("aes.key")}</div>
                     <CopyButton text=key/>
                 </div>
                 <div class="btn-row">
                     <input type="text" prop:value=key on:input=move |ev| set_key.set(event_target_value(&ev)) class="key-input" placeholder="64 hex chars"/>
-                    <button class="btn" on:click=generate_key>{move || match lang.get() { Lang::En => "Generate", Lang::Zh => "生成", }}</button>
+                    <button class="btn" on:click=generate_key>{move || lang.get().t("common.generate")}</button>
                 </div>
             </div>
             <div class="tool-grid">
                 <div class="box">
-                    <div class="box-header"><div class="box-label">{move || match lang.get() { Lang::En => "Plaintext", Lang::Zh => "明文", }}</div><ClearButton on_click=move || { set_input.set(String::new()); set_output.set(String::new()); }/></div>
-                    <textarea prop:value=input on:input=move |ev| set_input.set(event_target_value(&ev)) placeholder="..."></textarea>
-                    <div class="btn-row"><button class="btn" on:click=encrypt>{move || match lang.get() { Lang::En => "Encrypt →", Lang::Zh => "加密 →", }}</button></div>
+                    <div class="box-header"><div class="box-label">{move || lang.get().t("aes.plaintext")}</div><ClearButton on_click=move || { set_input.set(String::new()); set_output.set(String::new()); }/></div>
+                    <TextInput lang=lang value=input on_input=move |v| set_input.set(v)/>
+                    <div class="btn-row"><button class="btn" on:click=encrypt>{move || lang.get().t("aes.encrypt")}</button></div>
                 </div>
                 <div class="box">
-                    <div class="box-header"><div class="box-label">{move || match lang.get() { Lang::En => "Ciphertext", Lang::Zh => "密文", }}</div><CopyButton text=output/></div>
-                    <textarea prop:value=output on:input=move |ev| set_output.set(event_target_value(&ev)) placeholder="..."></textarea>
-                    <div class="btn-row"><button class="btn" on:click=decrypt>{move || match lang.get() { Lang::En => "← Decrypt", Lang::Zh => "← 解密", }}</button></div>
+                    <div class="box-header"><div class="box-label">{move || lang.get().t("aes.ciphertext")}</div><div class="header-buttons"><CopyButton text=output primary=true/><DownloadButton text=output filename="ciphertext.txt"/></div></div>
+                    <TextInput lang=lang value=output on_input=move |v| set_output.set(v)/>
+                    <div class="btn-row"><button class="btn" on:click=decrypt>{move || lang.get().t("aes.decrypt")}</button></div>
                 </div>
             </div>
-            {move || error.get().map(|e| view! { <div class="error">{e}</div> })}
+            {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
         </div>
     }
 }
@@ -827,87 +2126,42 @@ fn AesPage(lang: ReadSignal<Lang>) -> impl IntoView {
 // ==================== JWT Page ====================
 #[component]
 fn JwtPage(lang: ReadSignal<Lang>) -> impl IntoView {
-    use base64::{engine::general_purpose, Engine as _};
-    use hmac::{Hmac, Mac};
-
-    type HmacSha256 = Hmac<sha2::Sha256>;
-
     let (input, set_input) = create_signal(String::new());
     let (secret, set_secret) = create_signal(String::new());
     let (header, set_header) = create_signal(String::new());
     let (payload, set_payload) = create_signal(String::new());
-    let (signature, set_signature) = create_signal(String::new());
-    let (error, set_error) = create_signal(Option::<String>::None);
+    let (signature, set_signature) = create_signal(Option::<crypto::SignatureStatus>::None);
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
 
     let decode = move |val: String| {
         set_input.set(val.clone());
         set_error.set(None);
         set_header.set(String::new());
         set_payload.set(String::new());
-        set_signature.set(String::new());
+        set_signature.set(None);
 
-        let parts: Vec<&str> = val.split('.').collect();
-        if parts.len() < 2 {
-            if !val.is_empty() {
-                set_error.set(Some("Invalid JWT format".into()));
-            }
+        if val.is_empty() {
             return;
         }
 
-        let decode_part = |part: &str| -> Result<String, String> {
-            let bytes = general_purpose::URL_SAFE_NO_PAD
-                .decode(part)
-                .map_err(|e| format!("Base64 Error: {}", e))?;
-            let json_str = String::from_utf8(bytes).map_err(|e| format!("UTF-8 Error: {}", e))?;
-            let val: serde_json::Value =
-                serde_json::from_str(&json_str).map_err(|e| format!("JSON Error: {}", e))?;
-            Ok(serde_json::to_string_pretty(&val).unwrap())
-        };
-
-        match decode_part(parts[0]) {
-            Ok(h) => set_header.set(h),
-            Err(e) => {
-                set_error.set(Some(format!("Header: {}", e)));
-                return;
-            }
-        }
-        if let Ok(p) = decode_part(parts[1]) {
-            set_payload.set(p);
-        }
-
-        if parts.len() >= 3 && !secret.get().is_empty() {
-            let secret_key = secret.get();
-            let message = format!("{}.{}", parts[0], parts[1]);
-
-            let mac = HmacSha256::new_from_slice(secret_key.as_bytes())
-                .map_err(|e| format!("HMAC error: {}", e));
-
-            match mac {
-                Ok(mut m) => {
-                    m.update(message.as_bytes());
-                    let result = m.finalize().into_bytes();
-                    let expected_sig = general_purpose::URL_SAFE_NO_PAD.encode(&result[..]);
-                    let actual_sig = parts[2].to_string();
-
-                    if expected_sig == actual_sig {
-                        set_signature.set("✅ Signature Valid".to_string());
-                    } else {
-                        set_signature.set("❌ Signature Invalid".to_string());
-                    }
-                }
-                Err(e) => {
-                    set_signature.set(format!("Error: {}", e));
+        match crypto::jwt_decode(&val, &secret.get()) {
+            Ok(decoded) => {
+                set_header.set(decoded.header);
+                if let Some(p) = decoded.payload {
+                    set_payload.set(p);
                 }
+                set_signature.set(decoded.signature);
             }
+            Err(e) => set_error.set(Some(e)),
         }
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="JWT Decoder" title_zh="JWT 解碼"/>
+            <ToolHeader lang=lang tool=&crypto::JwtTool state=vec![state_field("input", input, SignalSetter::map(decode))] output=payload/>
             <div class="box" style="margin-bottom:20px">
                 <div class="box-header">
-                    <div class="box-label">{move || match lang.get() { Lang::En => "Secret (for signature verification)", Lang::Zh => "密鑰 (用於簽名驗證)", }}</div>
+                    <div class="box-label">{move || lang.get().t("jwt.secret")}</div>
                 </div>
                 <input
                     type="text"
@@ -917,16 +2171,16 @@ fn JwtPage(lang: ReadSignal<Lang>) -> impl IntoView {
                         set_secret.set(event_target_value(&ev));
                         decode(input.get());
                     }
-                    placeholder={move || match lang.get() { Lang::En => "Enter secret key", Lang::Zh => "輸入密鑰", }}
+                    placeholder={move || lang.get().t("jwt.secret-placeholder")}
                 />
             </div>
             <div class="box">
                 <div class="box-header">
                     <div class="box-label">"JWT Token"</div>
-                    <ClearButton on_click=move || { set_input.set(String::new()); set_header.set(String::new()); set_payload.set(String::new()); set_signature.set(String::new()); set_error.set(None); }/>
+                    <ClearButton on_click=move || { set_input.set(String::new()); set_header.set(String::new()); set_payload.set(String::new()); set_signature.set(None); set_error.set(None); }/>
                 </div>
-                <textarea prop:value=input on:input=move |ev| decode(event_target_value(&ev)) placeholder="eyJhbGci..."></textarea>
-                {move || error.get().map(|e| view! { <div class="error">{e}</div> })}
+                <TextInput lang=lang value=input on_input=move |v| decode(v) placeholder="eyJhbGci..."/>
+                {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
             </div>
             <div class="tool-grid">
                 <div class="box">
@@ -934,13 +2188,13 @@ fn JwtPage(lang: ReadSignal<Lang>) -> impl IntoView {
                     <textarea prop:value=header readonly placeholder="..."></textarea>
                 </div>
                 <div class="box">
-                    <div class="box-header"><div class="box-label">"Payload"</div><CopyButton text=payload/></div>
+                    <div class="box-header"><div class="box-label">"Payload"</div><div class="header-buttons"><CopyButton text=payload primary=true/><DownloadButton text=payload filename="payload.json"/></div></div>
                     <textarea prop:value=payload readonly placeholder="..."></textarea>
                 </div>
             </div>
             <div class="box">
-                <div class="box-header"><div class="box-label">{move || match lang.get() { Lang::En => "Signature", Lang::Zh => "簽名", }}</div></div>
-                <div class="jwt-signature">{signature}</div>
+                <div class="box-header"><div class="box-label">{move || lang.get().t("jwt.signature")}</div></div>
+                <div class="jwt-signature">{move || signature.get().map(|s| s.localized(lang.get()))}</div>
             </div>
         </div>
     }
@@ -952,20 +2206,20 @@ fn UuidPage(lang: ReadSignal<Lang>) -> impl IntoView {
     let (uuid_v4, set_uuid_v4) = create_signal(String::new());
 
     let generate = move |_| {
-        set_uuid_v4.set(uuid::Uuid::new_v4().to_string());
+        set_uuid_v4.set(development::generate_uuid());
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="UUID Generator" title_zh="UUID 生成器"/>
+            <ToolHeader lang=lang tool=&development::UuidTool/>
             <div class="box" style="text-align:center">
                 <div class="box-label">"UUID v4"</div>
                 <div class="uuid-display">
                     {move || { let current = uuid_v4.get(); if current.is_empty() { "Click to generate".into() } else { current } }}
                 </div>
                 <div class="btn-row" style="justify-content:center;margin-top:20px">
-                    <button class="btn" on:click=generate>{move || match lang.get() { Lang::En => "Generate", Lang::Zh => "生成", }}</button>
-                    <CopyButton text=uuid_v4/>
+                    <button class="btn" on:click=generate>{move || lang.get().t("common.generate")}</button>
+                    <CopyButton text=uuid_v4 primary=true/>
                 </div>
             </div>
         </div>
@@ -975,42 +2229,42 @@ fn UuidPage(lang: ReadSignal<Lang>) -> impl IntoView {
 // ==================== Regex Page ====================
 #[component]
 fn RegexPage(lang: ReadSignal<Lang>) -> impl IntoView {
-    use regex::Regex;
-
     let (pattern, set_pattern) = create_signal(String::new());
     let (text, set_text) = create_signal(String::new());
     let (result, set_result) = create_signal(String::new());
-    let (error, set_error) = create_signal(Option::<String>::None);
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
+    let runner = JobRunner::new();
 
     let test_regex = move |_| {
-        set_error.set(None);
-        match Regex::new(&pattern.get()) {
-            Ok(re) => {
-                let matches: Vec<_> = re
-                    .find_iter(&text.get())
-                    .map(|m| m.as_str().to_string())
-                    .collect();
-                set_result.set(if matches.is_empty() {
-                    "No match".into()
-                } else {
-                    matches.join(", ")
-                });
+        let job = Job::Regex {
+            pattern: pattern.get_untracked(),
+            text: text.get_untracked(),
+        };
+        runner.submit(job, &[], move |result| match result {
+            Ok(JobOutput::Matches(matches)) => {
+                set_error.set(None);
+                set_result.set(development::regex_summary(&matches));
             }
-            Err(e) => set_error.set(Some(e.to_string())),
-        }
+            Ok(_) => {}
+            Err(e) => set_error.set(Some(e)),
+        });
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Regex Tester" title_zh="正則測試"/>
+            <ToolHeader lang=lang tool=&development::RegexTool state=vec![
+                state_field("pattern", pattern, SignalSetter::map(move |v| { set_pattern.set(v); test_regex(()); })),
+                state_field("input", text, SignalSetter::map(move |v| { set_text.set(v); test_regex(()); })),
+            ] output=result/>
             <div class="box">
-                <div class="box-label">{move || match lang.get() { Lang::En => "Pattern", Lang::Zh => "正則表達式", }}</div>
+                <div class="box-label">{move || lang.get().t("regex.pattern")}</div>
                 <input type="text" prop:value=pattern on:input=move |ev| { set_pattern.set(event_target_value(&ev)); test_regex(()); } placeholder="^[a-z]+$" class="regex-input"/>
-                {move || error.get().map(|e| view! { <div class="error">{e}</div> })}
+                {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
             </div>
             <div class="box">
-                <div class="box-label">{move || match lang.get() { Lang::En => "Test Text", Lang::Zh => "測試文本", }}</div>
-                <textarea prop:value=text on:input=move |ev| { set_text.set(event_target_value(&ev)); test_regex(()); } placeholder="..."></textarea>
+                <div class="box-label">{move || lang.get().t("regex.test-text")}</div>
+                <TextInput lang=lang value=text on_input=move |v| { set_text.set(v); test_regex(()); }/>
+                <JobStatus lang=lang runner=runner/>
                 <div class="regex-result">{result}</div>
             </div>
         </div>
@@ -1020,39 +2274,31 @@ fn RegexPage(lang: ReadSignal<Lang>) -> impl IntoView {
 // ==================== Timestamp Page ====================
 #[component]
 fn TimestampPage(lang: ReadSignal<Lang>) -> impl IntoView {
-    use chrono::{TimeZone, Utc};
-
-    let (ts, set_ts) = create_signal(Utc::now().timestamp().to_string());
-    let (iso, set_iso) = create_signal(String::new());
+    let (ts, set_ts) = create_signal(development::now_timestamp().to_string());
+    let (iso, set_iso) = create_signal(Ok(String::new()));
 
     let convert = move || {
-        let s = ts.get();
-        match s.parse::<i64>() {
-            Ok(val) => match Utc.timestamp_opt(val, 0).single() {
-                Some(dt) => set_iso.set(dt.to_rfc3339()),
-                None => set_iso.set("Invalid".to_string()),
-            },
-            Err(_) => set_iso.set("Invalid".to_string()),
-        }
+        set_iso.set(development::timestamp_to_iso(&ts.get()));
     };
 
     let now_ts = move |_| {
-        set_ts.set(Utc::now().timestamp().to_string());
+        set_ts.set(development::now_timestamp().to_string());
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Timestamp" title_zh="時間戳"/>
+            <ToolHeader lang=lang tool=&development::TimestampTool state=vec![state_field("input", ts, SignalSetter::map(move |v| { set_ts.set(v); convert(); }))]
+                output=Signal::derive(move || iso.get().unwrap_or_default())/>
             <div class="box">
                 <div class="box-label">"Unix Timestamp"</div>
                 <div class="btn-row">
                     <input type="text" prop:value=ts on:input=move |ev| { set_ts.set(event_target_value(&ev)); convert(); } class="ts-input"/>
-                    <button class="btn" on:click=now_ts>{move || match lang.get() { Lang::En => "Now", Lang::Zh => "現在", }}</button>
+                    <button class="btn" on:click=now_ts>{move || lang.get().t("timestamp.now")}</button>
                 </div>
             </div>
             <div class="box">
                 <div class="box-label">"ISO 8601"</div>
-                <div class="iso-display">{iso}</div>
+                <div class="iso-display">{move || iso.get().unwrap_or_else(|e: ToolError| e.localized(lang.get()))}</div>
             </div>
         </div>
     }
@@ -1069,29 +2315,25 @@ fn BaseConvPage(lang: ReadSignal<Lang>) -> impl IntoView {
         if current.is_empty() {
             return String::new();
         }
-        u128::from_str_radix(&current, from_base.get())
-            .map(|n| match base {
-                2 => format!("{:b}", n),
-                8 => format!("{:o}", n),
-                10 => format!("{}", n),
-                16 => format!("{:x}", n),
-                _ => String::new(),
-            })
-            .unwrap_or_else(|_| "Error".into())
+        development::base_convert(&current, from_base.get(), base)
+            .unwrap_or_else(|e| e.localized(lang.get()))
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Base Converter" title_zh="進制轉換"/>
+            <ToolHeader lang=lang tool=&development::BaseConvTool state=vec![
+                state_field("input", val, set_val),
+                state_field("from", Signal::derive(move || from_base.get().to_string()), SignalSetter::map(move |v: String| if let Ok(base) = v.parse() { set_from.set(base) })),
+            ] output=Signal::derive(move || [10, 16, 2, 8].map(get_val).join(" "))/>
             <div class="box">
-                <div class="box-label">{move || match lang.get() { Lang::En => "Input Value", Lang::Zh => "輸入值", }}</div>
+                <div class="box-label">{move || lang.get().t("base-conv.input")}</div>
                 <div class="btn-row">
-                    <input type="text" on:input=move |ev| set_val.set(event_target_value(&ev)) class="base-input"/>
+                    <input type="text" prop:value=val on:input=move |ev| set_val.set(event_target_value(&ev)) class="base-input"/>
                     <select on:change=move |ev| set_from.set(event_target_value(&ev).parse().unwrap()) class="base-select">
-                        <option value="10">"Dec"</option>
-                        <option value="16">"Hex"</option>
-                        <option value="2">"Bin"</option>
-                        <option value="8">"Oct"</option>
+                        <option value="10" selected=move || from_base.get() == 10>"Dec"</option>
+                        <option value="16" selected=move || from_base.get() == 16>"Hex"</option>
+                        <option value="2" selected=move || from_base.get() == 2>"Bin"</option>
+                        <option value="8" selected=move || from_base.get() == 8>"Oct"</option>
                     </select>
                 </div>
             </div>
@@ -1105,41 +2347,232 @@ fn BaseConvPage(lang: ReadSignal<Lang>) -> impl IntoView {
     }
 }
 
+// ==================== Hex Dump Page ====================
+/// Bytes rendered as spans on the hex dump page; the text dump, the copy
+/// button and the parser have no limit.
+const HEX_VIEW_LIMIT: usize = 4 * 1024;
+
+/// Byte index of the `data-i` span holding a selection end point.
+fn selected_byte(node: Option<web_sys::Node>) -> Option<usize> {
+    let node = node?;
+    let element = match node.dyn_into::<web_sys::Element>() {
+        Ok(element) => element,
+        Err(node) => node.parent_element()?,
+    };
+    element
+        .closest("[data-i]")
+        .ok()??
+        .get_attribute("data-i")?
+        .parse()
+        .ok()
+}
+
+#[component]
+fn HexDumpPage(lang: ReadSignal<Lang>) -> impl IntoView {
+    let tool = &development::HexDumpTool;
+    let input = create_rw_signal(Vec::<u8>::new());
+    let options = create_rw_signal(load_prefs(tool));
+    let (source, set_source) = create_signal(String::new());
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
+    let (parsed, set_parsed) = create_signal(Option::<(bytes::DumpFormat, usize)>::None);
+    // Inclusive byte range selected in either column.
+    let selection = create_rw_signal(Option::<(usize, usize)>::None);
+
+    let layout = create_memo(move |_| options.with(development::dump_layout).unwrap_or_default());
+    let dump = Signal::derive(move || input.with(|b| bytes::hex_dump_with(b, layout.get())));
+    create_effect(move |_| {
+        input.track();
+        selection.set(None);
+    });
+
+    let on_select = move |_| {
+        let Some(current) = browser_window().and_then(|w| w.get_selection().ok().flatten()) else {
+            return;
+        };
+        if current.is_collapsed() {
+            selection.set(None);
+            return;
+        }
+        let anchor = selected_byte(current.anchor_node()).map(|i| (i, current.anchor_offset()));
+        let focus = selected_byte(current.focus_node()).map(|i| (i, current.focus_offset()));
+        let range = anchor.zip(focus).map(|(a, b)| {
+            let (start, (end, end_offset)) = if a <= b { (a.0, b) } else { (b.0, a) };
+            // Ending at the very start of a byte leaves that byte out.
+            let end = if end_offset == 0 && end > start {
+                end - 1
+            } else {
+                end
+            };
+            (start, end)
+        });
+        selection.set(range);
+    };
+
+    let parse = move |_| {
+        set_error.set(None);
+        set_parsed.set(None);
+        match bytes::parse_dump(&source.get()) {
+            Ok((b, format)) => {
+                set_parsed.set(Some((format, b.len())));
+                input.set(b);
+            }
+            Err(e) => set_error.set(Some(e)),
+        }
+    };
+
+    let edit = move |_| {
+        set_parsed.set(None);
+        set_source.set(dump.get_untracked());
+    };
+
+    let rows = move || {
+        let DumpLayout { width, group } = layout.get();
+        let (width, group) = (width.max(1), group.max(1));
+        let is_selected = move |i: usize| {
+            selection
+                .get()
+                .is_some_and(|(from, to)| (from..=to).contains(&i))
+        };
+        input.with(|b| {
+            b[..b.len().min(HEX_VIEW_LIMIT)]
+                .chunks(width)
+                .enumerate()
+                .map(|(line, chunk)| {
+                    let start = line * width;
+                    let hex = (0..width)
+                        .map(|j| {
+                            let gap = j > 0 && j % group == 0;
+                            match chunk.get(j) {
+                                Some(&byte) => view! {
+                                    <span data-i=start + j class:group-gap=gap class:selected=move || is_selected(start + j)>{format!("{byte:02x}")}</span>
+                                }.into_view(),
+                                None => view! { <span class="pad" class:group-gap=gap>"  "</span> }.into_view(),
+                            }
+                        })
+                        .collect_view();
+                    let ascii = chunk
+                        .iter()
+                        .enumerate()
+                        .map(|(j, &byte)| view! {
+                            <span data-i=start + j class:selected=move || is_selected(start + j)>{bytes::dump_char(byte).to_string()}</span>
+                        })
+                        .collect_view();
+                    view! {
+                        <div class="hexdump-row">
+                            <span class="hexdump-offset">{format!("{start:08x}")}</span>
+                            <span class="hexdump-hex">{hex}</span>
+                            <span class="hexdump-ascii">{ascii}</span>
+                        </div>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    let mut state = byte_state("input", "input-hex", input).to_vec();
+    state.push(state_field("dump", source, set_source));
+
+    view! {
+        <div class="tool-container">
+            <ToolHeader lang=lang tool=tool state=state output=dump/>
+            <div class="box">
+                <div class="pipeline-options">
+                    <ToolOptions lang=lang tool=tool options=options keys=&["width", "group"]/>
+                </div>
+            </div>
+            <div class="box">
+                <div class="box-header">
+                    <div class="box-label">{move || lang.get().t("common.input")}</div>
+                    <ClearButton on_click=move || { input.set(Vec::new()); set_parsed.set(None); }/>
+                </div>
+                <ByteField lang=lang bytes=input/>
+            </div>
+            <div class="box">
+                <div class="box-header">
+                    <div class="box-label">{move || lang.get().t("hexdump.dump")}</div>
+                    <div class="header-buttons"><CopyButton text=dump primary=true/><DownloadButton text=dump filename="hexdump.txt"/></div>
+                </div>
+                <div class="hexdump" on:mouseup=on_select>{rows}</div>
+                {move || (input.with(Vec::len) > HEX_VIEW_LIMIT).then(|| view! {
+                    <div class="byte-note">
+                        {lang.get().format("hexdump.truncated", &[("shown", &job::format_size(HEX_VIEW_LIMIT as f64)), ("size", &job::format_size(input.with(Vec::len) as f64))])}
+                    </div>
+                })}
+                {move || selection.get().map(|(from, to)| view! {
+                    <div class="hint">
+                        {lang.get().format("hexdump.selection", &[("count", &(to - from + 1).to_string()), ("from", &format!("{from:#x}")), ("to", &format!("{to:#x}"))])}
+                    </div>
+                })}
+            </div>
+            <div class="box">
+                <div class="box-header">
+                    <div class="box-label">{move || lang.get().t("hexdump.reverse")}</div>
+                    <ClearButton on_click=move || { set_source.set(String::new()); set_parsed.set(None); }/>
+                </div>
+                <textarea class="hex-input" prop:value=source on:input=move |ev| set_source.set(event_target_value(&ev)) placeholder="00000000: 4865 6c6c 6f  Hello"></textarea>
+                <div class="btn-row">
+                    <button class="btn" on:click=parse>{move || lang.get().t("hexdump.parse")}</button>
+                    <button class="btn" on:click=edit>{move || lang.get().t("hexdump.edit")}</button>
+                </div>
+                {move || parsed.get().map(|(format, count)| view! {
+                    <div class="hint">
+                        {lang.get().format("hexdump.parsed", &[("count", &count.to_string()), ("format", lang.get().t(format.name_id()))])}
+                    </div>
+                })}
+            </div>
+            {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
+        </div>
+    }
+}
+
 // ==================== Diff Page ====================
 #[component]
 fn DiffPage(lang: ReadSignal<Lang>) -> impl IntoView {
-    use similar::{ChangeTag, TextDiff};
+    use development::DiffTag;
 
     let (old_text, set_old) = create_signal(String::new());
     let (new_text, set_new) = create_signal(String::new());
+    let (lines, set_lines) = create_signal(Vec::new());
+    let runner = JobRunner::new();
+
+    create_effect(move |_| {
+        let job = Job::Diff {
+            old: old_text.get(),
+            new: new_text.get(),
+        };
+        runner.submit(job, &[], move |result| {
+            if let Ok(JobOutput::Diff(diff)) = result {
+                set_lines.set(diff);
+            }
+        });
+    });
 
     let diff_view = move || {
-        let old = old_text.get();
-        let new = new_text.get();
-        let diff = TextDiff::from_lines(&old, &new);
-        diff.iter_all_changes().map(|change| {
-            let (sign, color) = match change.tag() {
-                ChangeTag::Delete => ("-", "#f00"),
-                ChangeTag::Insert => ("+", "#0f0"),
-                ChangeTag::Equal => (" ", "#888"),
+        lines.get().into_iter().map(|line| {
+            let color = match line.tag {
+                DiffTag::Delete => "#f00",
+                DiffTag::Insert => "#0f0",
+                DiffTag::Equal => "#888",
             };
-            view! { <div style=format!("color:{}", color)> {sign} {change.value().to_string()} </div> }
+            view! { <div style=format!("color:{}", color)> {line.tag.sign()} {line.value} </div> }
         }).collect_view()
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Diff" title_zh="文本比對"/>
+            <ToolHeader lang=lang tool=&development::DiffTool state=vec![state_field("original", old_text, set_old), state_field("input", new_text, set_new)]
+                output=Signal::derive(move || lines.with(|l| l.iter().map(|line| format!("{}{}", line.tag.sign(), line.value)).collect::<Vec<_>>().join("\n")))/>
             <div class="tool-grid">
                 <div class="box">
                     <div class="box-label">"Original"</div>
-                    <textarea on:input=move |ev| set_old.set(event_target_value(&ev))></textarea>
+                    <TextInput lang=lang value=old_text on_input=move |v| set_old.set(v)/>
                 </div>
                 <div class="box">
                     <div class="box-label">"Modified"</div>
-                    <textarea on:input=move |ev| set_new.set(event_target_value(&ev))></textarea>
+                    <TextInput lang=lang value=new_text on_input=move |v| set_new.set(v)/>
                 </div>
             </div>
+            <JobStatus lang=lang runner=runner/>
             <div class="box diff-output">{diff_view}</div>
         </div>
     }
@@ -1149,42 +2582,26 @@ fn DiffPage(lang: ReadSignal<Lang>) -> impl IntoView {
 #[component]
 fn CronPage(lang: ReadSignal<Lang>) -> impl IntoView {
     let (cron_expr, set_cron) = create_signal(String::new());
-    let (description, set_desc) = create_signal(String::new());
+    let (description, set_desc) = create_signal(Ok(String::new()));
 
     let parse_cron = move |_| {
-        let expr = cron_expr.get();
-        let parts: Vec<&str> = expr.split_whitespace().collect();
-        if parts.len() < 5 {
-            set_desc.set("Invalid cron expression".to_string());
-            return;
-        }
-
-        let minute = parts[0];
-        let hour = parts[1];
-        let day = parts[2];
-        let month = parts[3];
-        let weekday = parts[4];
-
-        let desc = format!(
-            "Minute: {}, Hour: {}, Day: {}, Month: {}, Weekday: {}",
-            minute, hour, day, month, weekday
-        );
-        set_desc.set(desc);
+        set_desc.set(utilities::cron_describe(&cron_expr.get()));
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Cron Parser" title_zh="Cron 解析"/>
+            <ToolHeader lang=lang tool=&utilities::CronTool state=vec![state_field("input", cron_expr, SignalSetter::map(move |v| { set_cron.set(v); parse_cron(()); }))]
+                output=Signal::derive(move || description.get().unwrap_or_default())/>
             <div class="box">
                 <div class="box-label">"Cron Expression"</div>
                 <div class="btn-row">
                     <input type="text" prop:value=cron_expr on:input=move |ev| set_cron.set(event_target_value(&ev)) placeholder="* * * * *" class="cron-input"/>
-                    <button class="btn" on:click=move |_| parse_cron(())>{move || match lang.get() { Lang::En => "Parse", Lang::Zh => "解析", }}</button>
+                    <button class="btn" on:click=move |_| parse_cron(())>{move || lang.get().t("cron.parse")}</button>
                 </div>
             </div>
             <div class="box">
-                <div class="box-label">{move || match lang.get() { Lang::En => "Description", Lang::Zh => "描述", }}</div>
-                <div class="cron-desc">{description}</div>
+                <div class="box-label">{move || lang.get().t("common.description")}</div>
+                <div class="cron-desc">{move || description.get().unwrap_or_else(|e: ToolError| e.localized(lang.get()))}</div>
             </div>
         </div>
     }
@@ -1193,58 +2610,484 @@ fn CronPage(lang: ReadSignal<Lang>) -> impl IntoView {
 // ==================== Image Base64 Page ====================
 #[component]
 fn ImageBase64Page(lang: ReadSignal<Lang>) -> impl IntoView {
-    use base64::{engine::general_purpose, Engine as _};
-
     let (base64_out, set_base64) = create_signal(String::new());
     let (data_uri, set_data_uri) = create_signal(String::new());
     let (is_loading, set_loading) = create_signal(false);
 
+    let load_image = move |file: web_sys::File| {
+        set_loading.set(true);
+        spawn_local(async move {
+            if let Some(bytes) = read_blob(&file).await {
+                let encoded = encoding::base64_encode(&bytes);
+                set_data_uri.set(utilities::image_data_uri(&file.type_(), &encoded));
+                set_base64.set(encoded);
+            }
+            set_loading.set(false);
+        });
+    };
+
     let on_file_change = move |ev: ev::Event| {
         let target = event_target::<web_sys::HtmlInputElement>(&ev);
-        if let Some(files) = target.files() {
-            if let Some(file) = files.get(0) {
-                set_loading.set(true);
-                let file_type = file.type_();
-                let reader = web_sys::FileReader::new().unwrap();
-                let reader_c = reader.clone();
-
-                let onload =
-                    wasm_bindgen::closure::Closure::wrap(Box::new(move |_e: web_sys::Event| {
-                        let array_buffer = reader_c.result().unwrap();
-                        let uint8_array = js_sys::Uint8Array::new(&array_buffer);
-                        let bytes = uint8_array.to_vec();
-                        let encoded = general_purpose::STANDARD.encode(&bytes);
-                        set_base64.set(encoded.clone());
-                        set_data_uri.set(format!("data:{};base64,{}", file_type, encoded));
-                        set_loading.set(false);
-                    })
-                        as Box<dyn FnMut(_)>);
-
-                reader.set_onload(Some(onload.as_ref().unchecked_ref()));
-                reader.read_as_array_buffer(&file).unwrap();
-                onload.forget();
-            }
+        if let Some(file) = target.files().and_then(|files| files.get(0)) {
+            load_image(file);
         }
     };
 
     view! {
         <div class="tool-container">
-            <ToolHeader lang=lang title_en="Image Base64" title_zh="圖片 Base64"/>
-            <div class="box">
-                <div class="box-label">{move || match lang.get() { Lang::En => "Upload Image", Lang::Zh => "上傳圖片", }}</div>
-                <input type="file" accept="image/*" on:change=on_file_change class="file-input"/>
-                {move || if is_loading.get() { view! { <div class="loading">"..."</div> } } else { view! { <div></div> } }}
-            </div>
+            <ToolHeader lang=lang tool=&utilities::ImageBase64Tool/>
+            <FileDrop on_file=load_image>
+                <div class="box">
+                    <div class="box-label">{move || lang.get().t("image.upload")}</div>
+                    <input type="file" accept="image/*" on:change=on_file_change class="file-input"/>
+                    {move || if is_loading.get() { view! { <div class="loading">"..."</div> } } else { view! { <div></div> } }}
+                </div>
+            </FileDrop>
             <div class="tool-grid">
                 <div class="box">
                     <div class="box-header"><div class="box-label">"Base64"</div><CopyButton text=base64_out/></div>
                     <textarea prop:value=base64_out readonly placeholder="..."></textarea>
                 </div>
                 <div class="box">
-                    <div class="box-header"><div class="box-label">"Data URI"</div><CopyButton text=data_uri/></div>
+                    <div class="box-header"><div class="box-label">"Data URI"</div><div class="header-buttons"><CopyButton text=data_uri primary=true/><DownloadButton text=data_uri filename="data-uri.txt"/></div></div>
                     <textarea prop:value=data_uri readonly placeholder="..."></textarea>
                 </div>
             </div>
         </div>
     }
 }
+
+// ==================== Magic Page ====================
+#[component]
+fn MagicPage(lang: ReadSignal<Lang>) -> impl IntoView {
+    let (input, set_input) = create_signal(String::new());
+    let (selected, set_selected) = create_signal(0usize);
+
+    let candidates = create_memo(move |_| detect::detect(&input.get()));
+    let on_input = move |v: String| {
+        set_input.set(v);
+        set_selected.set(0);
+    };
+
+    let candidate_view = move |(i, candidate): (usize, detect::Candidate)| {
+        let kind = candidate.kind;
+        let href = format!(
+            "{}/{}#{}",
+            resolve_router_base(),
+            kind.slug(),
+            permalink::encode(&candidate.state)
+        );
+        let page = registry::find(kind.slug());
+        let preview = Signal::derive(move || candidate.preview.clone());
+        view! {
+            <div class="magic-candidate" class:active=move || selected.get() == i>
+                <button class="magic-pick" on:click=move |_| set_selected.set(i)>
+                    <span class="magic-kind">{move || lang.get().t(kind.name_id())}</span>
+                    <span class="magic-bar"><span style=format!("width:{}%", candidate.confidence)></span></span>
+                    <span class="magic-score">{format!("{}%", candidate.confidence)}</span>
+                </button>
+                <Show when=move || selected.get() == i>
+                    <div class="magic-preview">
+                        <div class="box-header">
+                            <div class="box-label">{move || lang.get().t("magic.preview")}</div>
+                            <div class="header-buttons">
+                                <CopyButton text=preview/>
+                                <A href=href.clone() class="btn">
+                                    {move || lang.get().format("magic.open", &[("tool", page.map(|p| p.name(lang.get())).unwrap_or_default())])}
+                                </A>
+                            </div>
+                        </div>
+                        <pre>{preview}</pre>
+                    </div>
+                </Show>
+            </div>
+        }
+    };
+
+    view! {
+        <div class="tool-container">
+            <div class="tool-title">
+                <h2 style="font-size:3rem;font-weight:900;margin:0">
+                    {move || lang.get().t("tool.magic")}
+                </h2>
+                <ShareButton fields=vec![state_field("input", input, SignalSetter::map(on_input))]/>
+            </div>
+            <div class="box">
+                <div class="box-header">
+                    <div class="box-label">{move || lang.get().t("common.input")}</div>
+                    <ClearButton on_click=move || on_input(String::new())/>
+                </div>
+                <TextInput lang=lang value=input on_input=on_input/>
+            </div>
+            <div class="box">
+                <div class="box-label">{move || lang.get().t("magic.candidates")}</div>
+                {move || {
+                    let found = candidates.get();
+                    if found.is_empty() {
+                        let message = if input.with(|i| i.trim().is_empty()) { "magic.empty" } else { "magic.none" };
+                        view! { <div class="history-empty">{move || lang.get().t(message)}</div> }.into_view()
+                    } else {
+                        found.into_iter().enumerate().map(candidate_view).collect_view()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
+
+// ==================== Settings Page ====================
+#[component]
+fn SettingsPage(lang: ReadSignal<Lang>) -> impl IntoView {
+    let (import_json, set_import_json) = create_signal(String::new());
+    let (mode, set_mode) = create_signal(ImportMode::Merge);
+
+    let imported = create_memo(move |_| {
+        let json = import_json.get();
+        if json.trim().is_empty() {
+            return None;
+        }
+        let bundle = Bundle::from_json(&json).and_then(|bundle| match &bundle.theme {
+            Some(code) if Theme::from_code(code).is_none() => Err(ToolError::InvalidOption {
+                key: THEME_KEY.to_string(),
+                value: code.clone(),
+            }),
+            _ => Ok(bundle),
+        });
+        Some(bundle)
+    });
+
+    let summary = move |bundle: &Bundle| {
+        let (recipes, tools, entries) = bundle.counts();
+        lang.get().format(
+            "settings.summary",
+            &[
+                ("recipes", &recipes.to_string()),
+                ("entries", &entries.to_string()),
+                ("tools", &tools.to_string()),
+            ],
+        )
+    };
+
+    let reload = || {
+        if let Some(window) = browser_window() {
+            let _ = window.location().reload();
+        }
+    };
+
+    let export = move |_| {
+        let bundle = Bundle::from_storage(stored_site_entries());
+        download_file(
+            "god-settings.json",
+            bundle.to_json().as_bytes(),
+            "application/json",
+        );
+    };
+
+    let import = move |_| {
+        let Some(Ok(bundle)) = imported.get_untracked() else {
+            return;
+        };
+        let current = Bundle::from_storage(stored_site_entries());
+        let result = current.import(bundle, mode.get_untracked());
+        clear_site_storage();
+        if let Some(storage) = local_storage() {
+            for (key, value) in result.to_storage() {
+                let _ = storage.set_item(&key, &value);
+            }
+        }
+        reload();
+    };
+
+    let clear_all = move |_| {
+        let confirmed = browser_window()
+            .and_then(|w| {
+                w.confirm_with_message(lang.get_untracked().t("settings.clear-confirm"))
+                    .ok()
+            })
+            .unwrap_or(false);
+        if confirmed {
+            clear_site_storage();
+            reload();
+        }
+    };
+
+    let mode_option = move |value: ImportMode, label: &'static str| {
+        view! {
+            <label class="pipeline-option">
+                <input type="radio" name="import-mode" prop:checked=move || mode.get() == value on:change=move |_| set_mode.set(value)/>
+                {move || lang.get().t(label)}
+            </label>
+        }
+    };
+
+    view! {
+        <div class="tool-container">
+            <div class="tool-title">
+                <h2 style="font-size:3rem;font-weight:900;margin:0">
+                    {move || lang.get().t("tool.settings")}
+                </h2>
+            </div>
+            <div class="box">
+                <div class="box-label">{move || lang.get().t("settings.export")}</div>
+                <p class="hint">{move || lang.get().t("settings.export-hint")}</p>
+                <div class="btn-row">
+                    <button class="btn" on:click=export>{move || lang.get().t("settings.export")}</button>
+                </div>
+            </div>
+            <div class="box">
+                <div class="box-header">
+                    <div class="box-label">{move || lang.get().t("settings.import")}</div>
+                    <ClearButton on_click=move || set_import_json.set(String::new())/>
+                </div>
+                <TextInput lang=lang value=import_json on_input=move |v| set_import_json.set(v) placeholder="god-settings.json"/>
+                {move || imported.get().map(|result| match result {
+                    Ok(bundle) => view! { <div class="hint">{summary(&bundle)}</div> }.into_view(),
+                    Err(e) => view! { <div class="error">{e.localized(lang.get())}</div> }.into_view(),
+                })}
+                <div class="btn-row">
+                    {mode_option(ImportMode::Merge, "settings.merge")}
+                    {mode_option(ImportMode::Replace, "settings.replace")}
+                    <button class="btn" disabled=move || !matches!(imported.get(), Some(Ok(_))) on:click=import>
+                        {move || lang.get().t("settings.import")}
+                    </button>
+                </div>
+            </div>
+            <div class="box">
+                <div class="box-label">{move || lang.get().t("settings.clear")}</div>
+                <p class="hint">{move || lang.get().t("settings.clear-hint")}</p>
+                <div class="btn-row">
+                    <button class="btn btn-danger" on:click=clear_all>{move || lang.get().t("settings.clear")}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+// ==================== Pipeline Page ====================
+#[component]
+fn PipelinePage(lang: ReadSignal<Lang>) -> impl IntoView {
+    let (input, set_input) = create_signal(String::new());
+    let (name, set_name) = create_signal(String::new());
+    let steps = create_rw_signal(vec![
+        Step::new("url-escape", Options::new().with("mode", "decode")),
+        Step::new("base64", Options::new().with("mode", "decode")),
+        Step::new("json", Options::new()),
+    ]);
+    let (recipe_json, set_recipe_json) = create_signal(String::new());
+    let (error, set_error) = create_signal(Option::<ToolError>::None);
+    let (name_missing, set_name_missing) = create_signal(false);
+    let (saved, set_saved) = create_signal(
+        load_stored::<Vec<Recipe>>(RECIPES_KEY)
+            .iter()
+            .map(Recipe::without_secrets)
+            .collect::<Vec<_>>(),
+    );
+
+    let results = create_memo(move |_| Recipe::new("", steps.get()).run(input.get().as_bytes()));
+    let final_output = Signal::derive(move || {
+        results.with(|r| match r.last() {
+            Some(Ok(output)) => output.to_text(),
+            _ => String::new(),
+        })
+    });
+
+    let load_recipe = move |recipe: Recipe| {
+        set_name.set(recipe.name);
+        steps.set(recipe.steps);
+    };
+
+    let import_json = move |json: String| {
+        set_error.set(None);
+        match Recipe::from_json(&json) {
+            Ok(recipe) => load_recipe(recipe),
+            Err(e) => set_error.set(Some(e)),
+        }
+    };
+
+    let export_json = move |_| {
+        let recipe = Recipe::new(&name.get(), steps.get()).without_secrets();
+        let json = recipe.to_json();
+        let filename = if recipe.name.is_empty() {
+            "recipe.json".to_string()
+        } else {
+            format!("{}.json", recipe.name)
+        };
+        download_file(&filename, json.as_bytes(), "application/json");
+        set_recipe_json.set(json);
+    };
+
+    let save_recipe = move |_| {
+        let recipe = Recipe::new(name.get().trim(), steps.get()).without_secrets();
+        if recipe.name.is_empty() {
+            set_error.set(None);
+            set_name_missing.set(true);
+            return;
+        }
+        set_error.set(None);
+        set_name_missing.set(false);
+        set_saved.update(|list| {
+            list.retain(|r| r.name != recipe.name);
+            list.push(recipe);
+            save_stored(RECIPES_KEY, list);
+        });
+    };
+
+    let on_file_change = move |ev: ev::Event| {
+        let target = event_target::<web_sys::HtmlInputElement>(&ev);
+        if let Some(file) = target.files().and_then(|files| files.get(0)) {
+            spawn_local(async move {
+                let json = read_blob(&file)
+                    .await
+                    .ok_or_else(|| ToolError::Read(file.name()))
+                    .and_then(|bytes| Ok(String::from_utf8(bytes)?));
+                match json {
+                    Ok(json) => {
+                        set_recipe_json.set(json.clone());
+                        import_json(json);
+                    }
+                    Err(e) => set_error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let step_view = move |(i, step): (usize, Step)| {
+        let tool = tools::find(&step.tool);
+        let option_views = tool
+            .map(|tool| {
+                tool.options()
+                    .iter()
+                    .map(|spec| {
+                        let key = spec.key;
+                        let current = step.options.value(spec).unwrap_or(spec.default).to_string();
+                        let on_change = move |ev| {
+                            let value = event_target_value(&ev);
+                            steps.update(|s| s[i].options.set(key, value));
+                        };
+                        let control = match spec.kind {
+                            OptionKind::Choice(choices) => view! {
+                                <select class="base-select" on:change=on_change>
+                                    {choices.iter().map(|c| view! {
+                                        <option value=*c selected=*c == current>
+                                            {move || lang.get().lookup(&format!("option.{key}.{c}")).unwrap_or(c)}
+                                        </option>
+                                    }).collect_view()}
+                                </select>
+                            }.into_view(),
+                            OptionKind::Text => view! {
+                                <input type="text" prop:value=current on:change=on_change/>
+                            }.into_view(),
+                            OptionKind::Secret => view! {
+                                <input type="password" prop:value=current on:change=on_change/>
+                            }.into_view(),
+                        };
+                        view! {
+                            <label class="pipeline-option">
+                                <span>{move || lang.get().lookup(&format!("option.{key}")).unwrap_or(key)}</span>
+                                {control}
+                            </label>
+                        }
+                    })
+                    .collect_view()
+            });
+
+        view! {
+            <div class="box pipeline-step">
+                <div class="box-header">
+                    <div class="btn-row">
+                        <span class="step-index">{format!("#{}", i + 1)}</span>
+                        <select class="base-select" on:change=move |ev| {
+                            let id = event_target_value(&ev);
+                            steps.update(|s| s[i] = Step::new(&id, Options::new()));
+                        }>
+                            {tools::all().iter().map(|t| {
+                                let id = t.id();
+                                view! { <option value=id selected=id == step.tool>{move || t.name(lang.get())}</option> }
+                            }).collect_view()}
+                        </select>
+                    </div>
+                    <div class="btn-row">
+                        <button class="clear-btn" aria-label="Move up" disabled=i == 0 on:click=move |_| steps.update(|s| s.swap(i, i - 1))>"↑"</button>
+                        <button class="clear-btn" aria-label="Move down" disabled=move || i + 1 >= steps.with(Vec::len) on:click=move |_| steps.update(|s| s.swap(i, i + 1))>"↓"</button>
+                        <button class="clear-btn" aria-label="Remove step" on:click=move |_| steps.update(|s| { s.remove(i); })>"✕"</button>
+                    </div>
+                </div>
+                <div class="pipeline-options">{option_views}</div>
+                {move || results.with(|r| match r.get(i) {
+                    Some(Ok(output)) => view! { <textarea class="pipeline-output" prop:value=output.to_text() readonly></textarea> }.into_view(),
+                    Some(Err(e)) => view! { <div class="error">{e.localized(lang.get())}</div> }.into_view(),
+                    None => view! { <div class="pipeline-skipped">"—"</div> }.into_view(),
+                })}
+            </div>
+        }
+    };
+
+    view! {
+        <div class="tool-container">
+            <div class="tool-title">
+                <h2 style="font-size:3rem;font-weight:900;margin:0">
+                    {move || lang.get().t("pipeline.title")}
+                </h2>
+                <ShareButton fields=vec![
+                    state_field("input", input, set_input),
+                    state_field(
+                        "recipe",
+                        Signal::derive(move || Recipe::new(&name.get(), steps.get()).without_secrets().to_json()),
+                        SignalSetter::map(move |json: String| if let Ok(recipe) = Recipe::from_json(&json) { load_recipe(recipe) }),
+                    ),
+                ]/>
+            </div>
+            <div class="box">
+                <div class="box-header">
+                    <div class="box-label">{move || lang.get().t("common.input")}</div>
+                    <ClearButton on_click=move || set_input.set(String::new())/>
+                </div>
+                <TextInput lang=lang value=input on_input=move |v| set_input.set(v)/>
+            </div>
+            {move || steps.get().into_iter().enumerate().map(step_view).collect_view()}
+            <div class="btn-row">
+                <button class="btn" on:click=move |_| steps.update(|s| s.push(Step::new("base64", Options::new())))>
+                    {move || lang.get().t("pipeline.add-step")}
+                </button>
+            </div>
+            <div class="box">
+                <div class="box-header">
+                    <div class="box-label">{move || lang.get().t("common.result")}</div>
+                    <div class="header-buttons"><CopyButton text=final_output primary=true/><DownloadButton text=final_output filename="output.txt"/></div>
+                </div>
+                <textarea prop:value=final_output readonly placeholder="..."></textarea>
+            </div>
+            <div class="box">
+                <div class="box-label">{move || lang.get().t("pipeline.recipe")}</div>
+                <div class="btn-row">
+                    <input type="text" class="key-input" prop:value=name on:input=move |ev| set_name.set(event_target_value(&ev))
+                        placeholder=move || lang.get().t("pipeline.recipe-name")/>
+                    <button class="btn" on:click=save_recipe>{move || lang.get().t("pipeline.save")}</button>
+                    <button class="btn" on:click=export_json>{move || lang.get().t("pipeline.export")}</button>
+                </div>
+                <div class="saved-recipes">
+                    {move || saved.get().into_iter().map(|recipe| {
+                        let label = recipe.name.clone();
+                        let remove_name = recipe.name.clone();
+                        view! {
+                            <span class="saved-recipe">
+                                <button class="nav-link" on:click=move |_| load_recipe(recipe.clone())>{label}</button>
+                                <button class="clear-btn" aria-label="Delete recipe" on:click=move |_| set_saved.update(|list| {
+                                    list.retain(|r| r.name != remove_name);
+                                    save_stored(RECIPES_KEY, list);
+                                })>"✕"</button>
+                            </span>
+                        }
+                    }).collect_view()}
+                </div>
+                <TextInput lang=lang value=recipe_json on_input=move |v| set_recipe_json.set(v) placeholder=r#"{"version":1,"steps":[...]}"#/>
+                <div class="btn-row">
+                    <button class="btn" on:click=move |_| import_json(recipe_json.get())>{move || lang.get().t("pipeline.import")}</button>
+                    <input type="file" accept="application/json,.json" on:change=on_file_change class="file-input"/>
+                </div>
+                {move || name_missing.get().then(|| view! { <div class="error">{move || lang.get().t("pipeline.name-required")}</div> })}
+                {move || error.get().map(|e| view! { <div class="error">{e.localized(lang.get())}</div> })}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,165 @@
+//! Command-line frontend over `tools`, so scripts get the same bytes as the
+//! browser pages.
+//!
+//! ```text
+//! god <tool> [<choice>...] [--<option> <value>]... [FILE|-]
+//! ```
+
+use do_everything_like_a_god::tools::{self, Lang, OptionKind, Options, Output, Tool};
+use std::io::{IsTerminal, Read, Write};
+use std::process::ExitCode;
+
+struct Invocation {
+    tool: &'static dyn Tool,
+    options: Options,
+    file: Option<String>,
+    newline: bool,
+}
+
+fn usage() -> String {
+    let mut out = String::from(
+        "Usage: god <tool> [<choice>...] [--<option> <value>]... [-n] [FILE|-]\n\n\
+         Reads FILE (or stdin) and writes the result to stdout.\n\
+         -n  do not print a trailing newline after text output\n\nTools:\n",
+    );
+    for tool in tools::all() {
+        out.push_str(&format!("  {:<14}{}\n", tool.id(), tool.name(Lang::En)));
+    }
+    out.push_str("\nRun `god help <tool>` for its options.\n");
+    out
+}
+
+fn tool_usage(tool: &dyn Tool) -> String {
+    let mut out = format!("Usage: god {} [options] [FILE|-]\n", tool.id());
+    for spec in tool.options() {
+        let values = match spec.kind {
+            OptionKind::Choice(choices) => choices.join("|"),
+            OptionKind::Text => "<text>".to_string(),
+            OptionKind::Secret => "<secret>".to_string(),
+        };
+        out.push_str(&format!("  --{} {}", spec.key, values));
+        if !spec.default.is_empty() {
+            out.push_str(&format!(" (default: {})", spec.default));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn parse_args(args: &[String]) -> Result<Invocation, String> {
+    let (id, rest) = args.split_first().ok_or_else(usage)?;
+    let tool = tools::find(id).ok_or_else(|| format!("unknown tool `{}`\n\n{}", id, usage()))?;
+
+    let mut options = Options::new();
+    let mut file = None;
+    let mut newline = true;
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "-n" {
+            newline = false;
+        } else if let Some(flag) = arg.strip_prefix("--") {
+            let (key, value) = match flag.split_once('=') {
+                Some((key, value)) => (key, value.to_string()),
+                None => (
+                    flag,
+                    iter.next()
+                        .ok_or_else(|| format!("missing value for --{}", flag))?
+                        .clone(),
+                ),
+            };
+            if !tool.options().iter().any(|spec| spec.key == key) {
+                return Err(format!("unknown option --{}\n\n{}", key, tool_usage(tool)));
+            }
+            options.set(key, value);
+        } else if let Some(spec) = tool.options().iter().find(
+            |spec| matches!(spec.kind, OptionKind::Choice(choices) if choices.contains(&arg.as_str())),
+        ) {
+            options.set(spec.key, arg.as_str());
+        } else if file.is_none() {
+            file = Some(arg.clone());
+        } else {
+            return Err(format!("unexpected argument `{}`", arg));
+        }
+    }
+
+    Ok(Invocation {
+        tool,
+        options,
+        file,
+        newline,
+    })
+}
+
+fn read_input(file: Option<&str>) -> std::io::Result<Vec<u8>> {
+    let mut input = Vec::new();
+    match file {
+        Some(path) if path != "-" => return std::fs::read(path),
+        Some(_) => {
+            std::io::stdin().read_to_end(&mut input)?;
+        }
+        None if !std::io::stdin().is_terminal() => {
+            std::io::stdin().read_to_end(&mut input)?;
+        }
+        None => {}
+    }
+    Ok(input)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None | Some("help" | "-h" | "--help") => {
+            match args.get(1).and_then(|id| tools::find(id)) {
+                Some(tool) => print!("{}", tool_usage(tool)),
+                None => print!("{}", usage()),
+            }
+            return ExitCode::SUCCESS;
+        }
+        Some("list") => {
+            for tool in tools::all() {
+                println!("{}", tool.id());
+            }
+            return ExitCode::SUCCESS;
+        }
+        _ => {}
+    }
+
+    let invocation = match parse_args(&args) {
+        Ok(invocation) => invocation,
+        Err(e) => {
+            eprintln!("god: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let input = match read_input(invocation.file.as_deref()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("god: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match invocation.tool.run(&input, &invocation.options) {
+        Ok(output) => {
+            let text_output = !matches!(output, Output::Bytes(_));
+            let mut bytes = output.into_bytes();
+            if text_output && invocation.newline {
+                bytes.push(b'\n');
+            }
+            let mut stdout = std::io::stdout().lock();
+            if stdout
+                .write_all(&bytes)
+                .and_then(|_| stdout.flush())
+                .is_err()
+            {
+                return ExitCode::FAILURE;
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("god: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
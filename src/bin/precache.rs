@@ -0,0 +1,68 @@
+//! Post-build step: writes the precache list of everything in the build
+//! output into `sw.js` and `precache-manifest.json`. Run it last, after any
+//! step that adds files.
+//!
+//! ```text
+//! precache <dist-dir>
+//! ```
+
+use do_everything_like_a_god::precache::{self, Precache};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::{env, fs, io};
+
+fn collect(root: &Path, dir: &Path, files: &mut Vec<(String, Vec<u8>)>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(root, &path, files)?;
+        } else {
+            let relative: PathBuf = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+            let name = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.push((name, fs::read(&path)?));
+        }
+    }
+    Ok(())
+}
+
+fn run(dist: &Path) -> Result<Precache, String> {
+    let mut files = Vec::new();
+    collect(dist, dist, &mut files).map_err(|e| e.to_string())?;
+    let precache = Precache::new(files.iter().map(|(p, c)| (p.as_str(), c.as_slice())));
+
+    let sw_path = dist.join("sw.js");
+    let template = fs::read_to_string(&sw_path).map_err(|e| format!("sw.js: {e}"))?;
+    let sw = precache::inject(&template, &precache)
+        .ok_or("sw.js has no `const PRECACHE = ...;` line")?;
+    fs::write(&sw_path, sw).map_err(|e| e.to_string())?;
+
+    let manifest = serde_json::to_string_pretty(&precache).map_err(|e| e.to_string())?;
+    fs::write(dist.join("precache-manifest.json"), manifest).map_err(|e| e.to_string())?;
+    Ok(precache)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [dist] = args.as_slice() else {
+        eprintln!("Usage: precache <dist-dir>");
+        return ExitCode::from(2);
+    };
+    match run(Path::new(dist)) {
+        Ok(precache) => {
+            println!(
+                "Precaching {} assets as version {}",
+                precache.assets.len(),
+                precache.version
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("precache: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
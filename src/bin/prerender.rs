@@ -0,0 +1,55 @@
+//! Post-build step: renders the home page and every registry page into
+//! `index.html` and `<slug>/index.html`, so they show content before the wasm
+//! loads. Needs the `ssr` feature; run it after `seo` and before `precache`.
+//!
+//! ```text
+//! prerender <dist-dir> <site-url> [lang]
+//! ```
+
+use do_everything_like_a_god::i18n::Lang;
+use do_everything_like_a_god::prerender;
+use do_everything_like_a_god::registry;
+use std::path::Path;
+use std::process::ExitCode;
+use std::{env, fs};
+
+fn run(dist: &Path, site_url: &str, lang: Lang) -> Result<(), String> {
+    let template = fs::read_to_string(dist.join("index.html")).map_err(|e| e.to_string())?;
+    let pages = std::iter::once(None).chain(registry::pages().iter().map(Some));
+    for page in pages {
+        let (head, body) = prerender::render(site_url, page, lang);
+        let html = prerender::fill(&template, &head, &body, lang)
+            .ok_or("index.html has no <html>, </head> or <body>")?;
+        let dir = match page {
+            Some(page) => dist.join(page.slug),
+            None => dist.to_path_buf(),
+        };
+        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        fs::write(dir.join("index.html"), html).map_err(|e| e.to_string())?;
+        println!("Rendered {}", registry::page_url(site_url, page));
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (dist, site_url, lang) = match args.as_slice() {
+        [dist, site_url] => (dist, site_url, Some(Lang::En)),
+        [dist, site_url, code] => (dist, site_url, Lang::from_code(code)),
+        _ => {
+            eprintln!("Usage: prerender <dist-dir> <site-url> [lang]");
+            return ExitCode::from(2);
+        }
+    };
+    let Some(lang) = lang else {
+        eprintln!("prerender: unknown language {}", args[2]);
+        return ExitCode::from(2);
+    };
+    match run(Path::new(dist), site_url, lang) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("prerender: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
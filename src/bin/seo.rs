@@ -0,0 +1,42 @@
+//! Post-build step for static hosting: writes `sitemap.xml` and a
+//! `<slug>/index.html` with page-specific meta tags for every registry entry.
+//!
+//! ```text
+//! seo <dist-dir> <site-url>
+//! ```
+
+use do_everything_like_a_god::registry;
+use std::path::Path;
+use std::process::ExitCode;
+use std::{env, fs};
+
+fn run(dist: &Path, site_url: &str) -> std::io::Result<()> {
+    let template = fs::read_to_string(dist.join("index.html"))?;
+    for page in registry::pages() {
+        let dir = dist.join(page.slug);
+        fs::create_dir_all(&dir)?;
+        fs::write(
+            dir.join("index.html"),
+            registry::seo_page(&template, page, site_url),
+        )?;
+        println!("Created {}/index.html", page.slug);
+    }
+    fs::write(dist.join("sitemap.xml"), registry::sitemap(site_url))?;
+    println!("Created sitemap.xml");
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [dist, site_url] = args.as_slice() else {
+        eprintln!("Usage: seo <dist-dir> <site-url>");
+        return ExitCode::from(2);
+    };
+    match run(Path::new(dist), site_url) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("seo: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
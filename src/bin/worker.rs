@@ -0,0 +1,81 @@
+//! Web Worker entry point, built by Trunk next to the app. Each message is a
+//! two-element array: a JSON `job::Request` and an optional input, either a
+//! `Uint8Array` or a `Blob` that is read slice by slice. Progress and the
+//! result are posted back as JSON `job::Reply`.
+
+use do_everything_like_a_god::tools::job::{self, Reply, Request, Source};
+use do_everything_like_a_god::tools::ToolError;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Blob, DedicatedWorkerGlobalScope, FileReaderSync, MessageEvent};
+
+/// Reads a `Blob` synchronously, one slice at a time.
+struct BlobSource {
+    blob: Blob,
+    reader: FileReaderSync,
+}
+
+impl Source for BlobSource {
+    fn size(&self) -> u64 {
+        self.blob.size() as u64
+    }
+
+    fn read(&mut self, offset: u64, len: usize) -> Result<Vec<u8>, ToolError> {
+        let error = |e: JsValue| ToolError::Read(format!("{e:?}"));
+        let slice = self
+            .blob
+            .slice_with_f64_and_f64(offset as f64, (offset + len as u64) as f64)
+            .map_err(error)?;
+        let buffer = self.reader.read_as_array_buffer(&slice).map_err(error)?;
+        Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+    }
+}
+
+fn post(scope: &DedicatedWorkerGlobalScope, reply: &Reply) {
+    if let Ok(json) = serde_json::to_string(reply) {
+        let _ = scope.post_message(&JsValue::from_str(&json));
+    }
+}
+
+fn main() {
+    console_error_panic_hook::set_once();
+    let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+    let reply_scope = scope.clone();
+
+    let onmessage = Closure::<dyn FnMut(MessageEvent)>::new(move |ev: MessageEvent| {
+        let message: js_sys::Array = ev.data().unchecked_into();
+        let Some(request) = message
+            .get(0)
+            .as_string()
+            .and_then(|json| serde_json::from_str::<Request>(&json).ok())
+        else {
+            return;
+        };
+
+        let id = request.id;
+        let mut progress = |done, total| {
+            post(&reply_scope, &Reply::Progress { id, done, total });
+        };
+        let input = message.get(1);
+        let result = match input.dyn_into::<Blob>() {
+            Ok(blob) => match FileReaderSync::new() {
+                Ok(reader) => job::run_from(
+                    &request.job,
+                    &mut BlobSource { blob, reader },
+                    &mut progress,
+                ),
+                Err(e) => Err(ToolError::Read(format!("{e:?}"))),
+            },
+            Err(input) => {
+                let data = input
+                    .dyn_into::<js_sys::Uint8Array>()
+                    .map(|bytes| bytes.to_vec())
+                    .unwrap_or_default();
+                job::run(&request.job, &data, &mut progress)
+            }
+        };
+        post(&reply_scope, &Reply::Done { id, result });
+    });
+    scope.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    onmessage.forget();
+}
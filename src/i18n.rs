@@ -0,0 +1,134 @@
+//! Message catalogs keyed by message id, one JSON file per language under
+//! `locales/`. Messages may contain `{name}` placeholders.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Lang {
+    En,
+    /// Traditional Chinese.
+    Zh,
+    /// Simplified Chinese.
+    ZhHans,
+    Ja,
+}
+
+const SOURCES: [&str; 4] = [
+    include_str!("../locales/en.json"),
+    include_str!("../locales/zh-TW.json"),
+    include_str!("../locales/zh-CN.json"),
+    include_str!("../locales/ja.json"),
+];
+
+fn catalog(lang: Lang) -> &'static HashMap<String, String> {
+    static CATALOGS: OnceLock<Vec<HashMap<String, String>>> = OnceLock::new();
+    let catalogs = CATALOGS.get_or_init(|| {
+        SOURCES
+            .iter()
+            .map(|source| serde_json::from_str(source).expect("locale files are valid JSON"))
+            .collect()
+    });
+    &catalogs[lang as usize]
+}
+
+impl Lang {
+    pub const ALL: [Lang; 4] = [Lang::En, Lang::Zh, Lang::ZhHans, Lang::Ja];
+
+    /// BCP 47 tag, also used as the persisted value.
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Zh => "zh-TW",
+            Lang::ZhHans => "zh-CN",
+            Lang::Ja => "ja",
+        }
+    }
+
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Lang::En => "English",
+            Lang::Zh => "繁體中文",
+            Lang::ZhHans => "简体中文",
+            Lang::Ja => "日本語",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Lang> {
+        Self::ALL.into_iter().find(|l| l.code() == code)
+    }
+
+    /// Picks the closest catalog for a tag such as `navigator.language`;
+    /// unknown languages fall back to English.
+    pub fn negotiate(tag: &str) -> Lang {
+        let tag = tag.trim().replace('_', "-").to_ascii_lowercase();
+        let mut subtags = tag.split('-');
+        match subtags.next() {
+            Some("zh") => {
+                let rest: Vec<&str> = subtags.collect();
+                if rest
+                    .iter()
+                    .any(|s| matches!(*s, "hant" | "tw" | "hk" | "mo"))
+                {
+                    Lang::Zh
+                } else {
+                    Lang::ZhHans
+                }
+            }
+            Some("ja") => Lang::Ja,
+            _ => Lang::En,
+        }
+    }
+
+    /// Looks up `id` in this catalog, falling back to English.
+    pub fn lookup(self, id: &str) -> Option<&'static str> {
+        catalog(self)
+            .get(id)
+            .or_else(|| catalog(Lang::En).get(id))
+            .map(String::as_str)
+    }
+
+    /// Translates `id`; unknown ids are returned unchanged.
+    pub fn t(self, id: &str) -> &str {
+        self.lookup(id).unwrap_or(id)
+    }
+
+    /// Translates `id` and fills its `{name}` placeholders from `args`.
+    pub fn format(self, id: &str, args: &[(&str, &str)]) -> String {
+        let mut out = String::new();
+        let mut rest = self.t(id);
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let arg = after.find('}').and_then(|end| {
+                let name = &after[..end];
+                args.iter()
+                    .find(|(n, _)| *n == name)
+                    .map(|(_, v)| (end, *v))
+            });
+            match arg {
+                Some((end, value)) => {
+                    out.push_str(value);
+                    rest = &after[end + 1..];
+                }
+                None => {
+                    out.push('{');
+                    rest = after;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// Ids present in the English catalog but missing from `lang`.
+pub fn missing_messages(lang: Lang) -> Vec<&'static str> {
+    let mut missing: Vec<&'static str> = catalog(Lang::En)
+        .keys()
+        .filter(|id| !catalog(lang).contains_key(*id))
+        .map(String::as_str)
+        .collect();
+    missing.sort_unstable();
+    missing
+}
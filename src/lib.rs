@@ -1 +1,8 @@
+pub mod api;
 pub mod app;
+pub mod i18n;
+pub mod precache;
+pub mod prerender;
+pub mod registry;
+pub mod tools;
+pub mod worker;
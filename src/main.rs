@@ -1,8 +1,43 @@
-mod app;
-use app::*;
+use do_everything_like_a_god::app::*;
+use leptos::leptos_dom::HydrationCtx;
 use leptos::*;
 
+/// Whether `body` holds the markup written by the `prerender` step.
+fn is_prerendered(body: &web_sys::HtmlElement) -> bool {
+    body.query_selector("[data-hk]").ok().flatten().is_some()
+}
+
+/// Renders the app into the emptied `body`. A `hydrate` build's
+/// `mount_to_body` only takes over existing markup, so it appends the view
+/// itself.
+fn mount_fresh(body: &web_sys::HtmlElement) {
+    HydrationCtx::stop_hydrating();
+    body.set_inner_html("");
+    #[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+    {
+        use leptos::leptos_dom::Mountable;
+        let app = view! { <App/> }.into_view();
+        let _ = body.append_child(&app.get_mountable_node());
+        std::mem::forget(app);
+    }
+    #[cfg(not(all(target_arch = "wasm32", feature = "hydrate")))]
+    mount_to_body(App);
+}
+
 pub fn main() {
     console_error_panic_hook::set_once();
-    mount_to_body(App);
+    // The JavaScript API, for trying things out in the devtools console.
+    let _ = js_sys::Reflect::set(
+        &window(),
+        &"god".into(),
+        &do_everything_like_a_god::api::namespace(),
+    );
+    let body = document().body().expect("document has a body");
+    if cfg!(feature = "hydrate") && is_prerendered(&body) {
+        // Takes over the pre-rendered markup in place.
+        mount_to_body(App);
+    } else {
+        // Without pre-rendered markup (`trunk serve`), or in a `csr` build.
+        mount_fresh(&body);
+    }
 }
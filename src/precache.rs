@@ -0,0 +1,67 @@
+//! Build-time precache list for the service worker. The `precache` binary
+//! collects the built files, and `inject` writes the list into `sw.js`, so
+//! every deploy changes the worker script and triggers an update.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Files in the build output that are not precached.
+const SKIPPED: [&str; 4] = [
+    "sw.js",
+    "robots.txt",
+    "sitemap.xml",
+    "precache-manifest.json",
+];
+
+/// The line of `sw.js` that is replaced with the generated list.
+const PLACEHOLDER_START: &str = "const PRECACHE = ";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Precache {
+    /// Hash of every precached path and its content.
+    pub version: String,
+    /// URLs relative to the service worker; `./` is the app root.
+    pub assets: Vec<String>,
+}
+
+impl Precache {
+    /// `files` are `(path, content)` pairs with `/`-separated paths relative
+    /// to the output directory.
+    pub fn new<'a>(files: impl IntoIterator<Item = (&'a str, &'a [u8])>) -> Self {
+        let mut files: Vec<(&str, &[u8])> = files
+            .into_iter()
+            .filter(|(path, _)| !SKIPPED.contains(path))
+            .collect();
+        files.sort_by_key(|(path, _)| *path);
+
+        let mut hasher = Sha256::new();
+        for (path, content) in &files {
+            hasher.update((path.len() as u64).to_le_bytes());
+            hasher.update(path.as_bytes());
+            hasher.update((content.len() as u64).to_le_bytes());
+            hasher.update(content);
+        }
+        let version = hex::encode(hasher.finalize())[..12].to_string();
+
+        let mut assets = vec!["./".to_string()];
+        assets.extend(files.iter().map(|(path, _)| path.to_string()));
+        Precache { version, assets }
+    }
+}
+
+/// Replaces the `const PRECACHE = ...;` line of the `sw.js` template.
+pub fn inject(template: &str, precache: &Precache) -> Option<String> {
+    let start = template.find(PLACEHOLDER_START)?;
+    let end = start
+        + template[start..]
+            .find('\n')
+            .unwrap_or(template.len() - start);
+    let json = serde_json::to_string(precache).ok()?;
+    Some(format!(
+        "{}{}{};{}",
+        &template[..start],
+        PLACEHOLDER_START,
+        json,
+        &template[end..]
+    ))
+}
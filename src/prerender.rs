@@ -0,0 +1,111 @@
+//! Static pre-rendering. With the `ssr` feature, `render` runs `App` on the
+//! host for one route, and `fill` writes the result into the built
+//! `index.html`, so every page has real markup before the wasm loads. The app
+//! hydrates that markup when it starts.
+
+use crate::i18n::Lang;
+use crate::registry;
+
+/// Puts the rendered `head` tags and `body` markup into the built `index.html`.
+/// The template's `<title>`, its `registry::PAGE_META` tags, its `<html lang>`
+/// and anything already in `<body>` are replaced, so filling a filled page
+/// gives the same result.
+pub fn fill(template: &str, head: &str, body: &str, lang: Lang) -> Option<String> {
+    let html = strip_page_meta(template);
+    let html = set_html_lang(&html, lang.code())?;
+
+    let head_end = html.find("</head>")?;
+    let body_start = head_end + html[head_end..].find("<body")?;
+    let body_open_end = body_start + html[body_start..].find('>')? + 1;
+    let body_end = body_open_end + html[body_open_end..].find("</body>")?;
+
+    Some(format!(
+        "{}{}\n    {}{}{}",
+        &html[..head_end],
+        head,
+        &html[head_end..body_open_end],
+        body,
+        &html[body_end..]
+    ))
+}
+
+/// Removes `<title>` and the `PAGE_META` tags, along with their lines.
+fn strip_page_meta(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        let tag = &rest[start..];
+        let end = if tag.starts_with("<title>") {
+            tag.find("</title>").map(|end| end + "</title>".len())
+        } else if is_page_meta(tag) {
+            tag.find('>').map(|end| end + 1)
+        } else {
+            None
+        };
+        match end {
+            Some(end) => {
+                out.truncate(out.trim_end_matches([' ', '\t']).len());
+                rest = &tag[end..];
+                rest = rest.strip_prefix('\n').unwrap_or(rest);
+            }
+            None => {
+                out.push('<');
+                rest = &tag[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_page_meta(tag: &str) -> bool {
+    let element = &tag[..tag.find('>').unwrap_or(tag.len())];
+    registry::PAGE_META.iter().any(|(name, attr)| {
+        element
+            .strip_prefix('<')
+            .and_then(|e| e.strip_prefix(name))
+            .is_some_and(|e| e.starts_with(char::is_whitespace))
+            && element.contains(&format!(" {attr}"))
+    })
+}
+
+fn set_html_lang(html: &str, code: &str) -> Option<String> {
+    let start = html.find("<html")?;
+    let end = start + html[start..].find('>')?;
+    let open = &html[start..end];
+    let open = match open.find(" lang=\"") {
+        Some(attr) => {
+            let value = attr + " lang=\"".len();
+            let value_end = value + open[value..].find('"')?;
+            format!("{}{}{}", &open[..value], code, &open[value_end..])
+        }
+        None => format!("{open} lang=\"{code}\""),
+    };
+    Some(format!("{}{}{}", &html[..start], open, &html[end..]))
+}
+
+/// Renders `App` at the route of `page` (the home page for `None`) and
+/// returns the `<head>` tags and the `<body>` markup.
+#[cfg(feature = "ssr")]
+pub fn render(site_url: &str, page: Option<&registry::ToolPage>, lang: Lang) -> (String, String) {
+    use crate::app::{App, PrerenderContext};
+    use leptos::*;
+    use leptos_router::{RouterIntegrationContext, ServerIntegration};
+
+    leptos::leptos_dom::HydrationCtx::reset_id();
+    let runtime = create_runtime();
+    let site_url = site_url.to_string();
+    let path = registry::page_url(&site_url, page);
+    let rendered = run_as_child(move || {
+        provide_context(PrerenderContext { site_url, lang });
+        provide_context(RouterIntegrationContext::new(ServerIntegration { path }));
+        leptos_meta::provide_meta_context();
+
+        let body = view! { <App/> }.into_view().render_to_string().to_string();
+        let (head, _) = leptos_meta::generate_head_metadata_separated();
+        (head, body)
+    });
+    runtime.dispose();
+    rendered
+}
@@ -0,0 +1,390 @@
+//! Every page of the site in one place. The sidebar and routes are built from
+//! it at runtime, and the `seo` binary uses it to write the sitemap and the
+//! per-route HTML entry points after `trunk build`.
+
+use crate::i18n::Lang;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Category {
+    Encoders,
+    Crypto,
+    Development,
+    Utilities,
+}
+
+impl Category {
+    pub const ALL: [Category; 4] = [
+        Category::Encoders,
+        Category::Crypto,
+        Category::Development,
+        Category::Utilities,
+    ];
+
+    /// Catalog id of the sidebar heading.
+    pub fn title_id(self) -> &'static str {
+        match self {
+            Category::Encoders => "nav.encoders",
+            Category::Crypto => "nav.crypto",
+            Category::Development => "nav.development",
+            Category::Utilities => "nav.utilities",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToolPage {
+    /// Route path segment; equal to the tool id where there is one.
+    pub slug: &'static str,
+    pub category: Category,
+    /// Sidebar label.
+    pub label: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub keywords: &'static [&'static str],
+}
+
+impl ToolPage {
+    /// Localized name from the `tool.<slug>` catalog entry.
+    pub fn name(&self, lang: Lang) -> &'static str {
+        lang.lookup(&format!("tool.{}", self.slug))
+            .unwrap_or(self.label)
+    }
+
+    /// Localized `title` from the `page.<slug>.title` catalog entry.
+    pub fn localized_title(&self, lang: Lang) -> &'static str {
+        lang.lookup(&format!("page.{}.title", self.slug))
+            .unwrap_or(self.title)
+    }
+
+    /// Localized `description` from the `page.<slug>.description` catalog entry.
+    pub fn localized_description(&self, lang: Lang) -> &'static str {
+        lang.lookup(&format!("page.{}.description", self.slug))
+            .unwrap_or(self.description)
+    }
+}
+
+/// `(element, attribute)` of the per-page head tags, other than `<title>`,
+/// that the app renders for each route.
+pub const PAGE_META: [(&str, &str); 10] = [
+    ("meta", "name=\"title\""),
+    ("meta", "name=\"description\""),
+    ("meta", "name=\"keywords\""),
+    ("meta", "property=\"og:title\""),
+    ("meta", "property=\"og:description\""),
+    ("meta", "property=\"og:url\""),
+    ("meta", "property=\"twitter:title\""),
+    ("meta", "property=\"twitter:description\""),
+    ("meta", "property=\"twitter:url\""),
+    ("link", "rel=\"canonical\""),
+];
+
+/// Document title of a tool page, or of the home page for `None`.
+pub fn document_title(page: Option<&ToolPage>, lang: Lang) -> String {
+    match page {
+        Some(page) => format!("{} | {}", page.localized_title(lang), lang.t("home.title")),
+        None => lang.t("home.page-title").to_string(),
+    }
+}
+
+/// Meta description of a tool page, or of the home page for `None`.
+pub fn document_description(page: Option<&ToolPage>, lang: Lang) -> &'static str {
+    match page {
+        Some(page) => page.localized_description(lang),
+        None => lang.lookup("home.description").unwrap_or_default(),
+    }
+}
+
+/// Canonical URL of a tool page, or of the site root for `None`.
+pub fn page_url(site_url: &str, page: Option<&ToolPage>) -> String {
+    let root = site_url.trim_end_matches('/');
+    match page {
+        Some(page) => format!("{root}/{}", page.slug),
+        None => format!("{root}/"),
+    }
+}
+
+static PAGES: &[ToolPage] = &[
+    ToolPage {
+        slug: "base64",
+        category: Category::Encoders,
+        label: "Base64",
+        title: "Base64 Encoder/Decoder",
+        description: "Online Base64 encoder and decoder tool.",
+        keywords: &["base64", "encode", "decode"],
+    },
+    ToolPage {
+        slug: "base32",
+        category: Category::Encoders,
+        label: "Base32",
+        title: "Base32 Encoder",
+        description: "Online Base32 encoding tool.",
+        keywords: &["base32", "encode", "decode"],
+    },
+    ToolPage {
+        slug: "base58",
+        category: Category::Encoders,
+        label: "Base58",
+        title: "Base58 Encoder",
+        description: "Online Base58 encoding tool.",
+        keywords: &["base58", "bitcoin", "encode", "decode"],
+    },
+    ToolPage {
+        slug: "ascii85",
+        category: Category::Encoders,
+        label: "Ascii85 / Z85",
+        title: "Ascii85 and Z85 Encoder/Decoder",
+        description: "Online Ascii85 (PDF, PostScript) and Z85 (ZeroMQ) encoder and decoder.",
+        keywords: &["ascii85", "base85", "z85", "pdf", "zeromq"],
+    },
+    ToolPage {
+        slug: "base45",
+        category: Category::Encoders,
+        label: "Base45",
+        title: "Base45 Encoder/Decoder",
+        description: "Online Base45 encoder and decoder for QR code payloads such as EU health certificates.",
+        keywords: &["base45", "qr code", "rfc 9285", "encode", "decode"],
+    },
+    ToolPage {
+        slug: "base36",
+        category: Category::Encoders,
+        label: "Base36",
+        title: "Base36 Encoder/Decoder",
+        description: "Online Base36 encoder and decoder for short IDs.",
+        keywords: &["base36", "short id", "encode", "decode"],
+    },
+    ToolPage {
+        slug: "base62",
+        category: Category::Encoders,
+        label: "Base62",
+        title: "Base62 Encoder/Decoder",
+        description: "Online Base62 encoder and decoder for short IDs and URLs.",
+        keywords: &["base62", "short id", "url shortener", "encode", "decode"],
+    },
+    ToolPage {
+        slug: "base91",
+        category: Category::Encoders,
+        label: "Base91",
+        title: "Base91 Encoder/Decoder",
+        description: "Online basE91 encoder and decoder.",
+        keywords: &["base91", "encode", "decode"],
+    },
+    ToolPage {
+        slug: "html-escape",
+        category: Category::Encoders,
+        label: "HTML Escape",
+        title: "HTML Entity Encoder",
+        description: "Online HTML escape tool for special characters.",
+        keywords: &["html", "escape", "entities"],
+    },
+    ToolPage {
+        slug: "url-escape",
+        category: Category::Encoders,
+        label: "URL Escape",
+        title: "URL Encoder",
+        description: "Online URL encoding and decoding tool.",
+        keywords: &["url", "percent-encoding", "escape"],
+    },
+    ToolPage {
+        slug: "hash",
+        category: Category::Crypto,
+        label: "Hash",
+        title: "Hash Generator",
+        description: "Online hash generator with MD5, SHA-1, SHA-256, etc.",
+        keywords: &["hash", "md5", "sha1", "sha256", "sha512"],
+    },
+    ToolPage {
+        slug: "aes",
+        category: Category::Crypto,
+        label: "AES",
+        title: "AES Encryption",
+        description: "Online AES encryption and decryption tool.",
+        keywords: &["aes", "aes-gcm", "encrypt", "decrypt"],
+    },
+    ToolPage {
+        slug: "jwt",
+        category: Category::Crypto,
+        label: "JWT",
+        title: "JWT Decoder",
+        description: "Online JWT decoder tool.",
+        keywords: &["jwt", "json web token", "decode"],
+    },
+    ToolPage {
+        slug: "json",
+        category: Category::Development,
+        label: "JSON",
+        title: "JSON Formatter",
+        description: "Online JSON formatter, validator, and minifier.",
+        keywords: &["json", "format", "validate", "minify"],
+    },
+    ToolPage {
+        slug: "regex",
+        category: Category::Development,
+        label: "Regex",
+        title: "Regex Tester",
+        description: "Online regular expression tester.",
+        keywords: &["regex", "regular expression", "test"],
+    },
+    ToolPage {
+        slug: "diff",
+        category: Category::Development,
+        label: "Diff",
+        title: "Diff Checker",
+        description: "Online text difference comparison tool.",
+        keywords: &["diff", "compare", "text"],
+    },
+    ToolPage {
+        slug: "uuid",
+        category: Category::Development,
+        label: "UUID",
+        title: "UUID Generator",
+        description: "Online UUID generator tool.",
+        keywords: &["uuid", "guid", "generator"],
+    },
+    ToolPage {
+        slug: "timestamp",
+        category: Category::Development,
+        label: "Timestamp",
+        title: "Timestamp Converter",
+        description: "Online Unix timestamp converter.",
+        keywords: &["timestamp", "unix time", "epoch"],
+    },
+    ToolPage {
+        slug: "base-conv",
+        category: Category::Development,
+        label: "Base Conv",
+        title: "Base Converter",
+        description: "Online number base converter.",
+        keywords: &["binary", "octal", "hex", "radix"],
+    },
+    ToolPage {
+        slug: "hexdump",
+        category: Category::Development,
+        label: "Hex Dump",
+        title: "Hex Dump Viewer and Editor",
+        description: "View text or files as an xxd-style hex dump, and turn xxd, od -x, C array or hex dumps back into bytes.",
+        keywords: &["hex dump", "xxd", "od", "hex editor", "bytes"],
+    },
+    ToolPage {
+        slug: "magic",
+        category: Category::Utilities,
+        label: "Magic",
+        title: "Magic Data Detector",
+        description: "Paste anything to find out whether it is Base64, Base58, hex, a JWT, a UUID, a timestamp, URL-encoded or JSON.",
+        keywords: &["detect", "identify", "decode", "magic"],
+    },
+    ToolPage {
+        slug: "cron",
+        category: Category::Utilities,
+        label: "Cron",
+        title: "Cron Expression Parser",
+        description: "Online cron expression parser.",
+        keywords: &["cron", "crontab", "schedule"],
+    },
+    ToolPage {
+        slug: "image-base64",
+        category: Category::Utilities,
+        label: "Image Base64",
+        title: "Image to Base64",
+        description: "Online image to Base64 converter.",
+        keywords: &["image", "base64", "data uri"],
+    },
+    ToolPage {
+        slug: "pipeline",
+        category: Category::Utilities,
+        label: "Pipeline",
+        title: "Recipe Pipeline",
+        description: "Chain encoders, decoders and formatters into reusable recipes.",
+        keywords: &["pipeline", "recipe", "chain"],
+    },
+    ToolPage {
+        slug: "settings",
+        category: Category::Utilities,
+        label: "Settings",
+        title: "Settings",
+        description: "Export, import or clear your theme, language, saved recipes and history.",
+        keywords: &["settings", "export", "import", "backup"],
+    },
+];
+
+pub fn pages() -> &'static [ToolPage] {
+    PAGES
+}
+
+pub fn find(slug: &str) -> Option<&'static ToolPage> {
+    PAGES.iter().find(|p| p.slug == slug)
+}
+
+pub fn in_category(category: Category) -> impl Iterator<Item = &'static ToolPage> {
+    PAGES.iter().filter(move |p| p.category == category)
+}
+
+/// `site_url` is the deployed root, e.g. `https://example.com/app/`.
+pub fn sitemap(site_url: &str) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    let entry = |loc: String, priority: &str| {
+        format!(
+            "    <url>\n        <loc>{loc}</loc>\n        <changefreq>weekly</changefreq>\n        <priority>{priority}</priority>\n    </url>\n"
+        )
+    };
+    out.push_str(&entry(page_url(site_url, None), "1.0"));
+    for page in PAGES {
+        out.push_str(&entry(page_url(site_url, Some(page)), "0.9"));
+    }
+    out.push_str("</urlset>\n");
+    out
+}
+
+/// Rewrites the title, description, keywords, canonical and social meta tags
+/// of the built `index.html` for `page`.
+pub fn seo_page(template: &str, page: &ToolPage, site_url: &str) -> String {
+    let title = html_escape::encode_double_quoted_attribute(page.title);
+    let description = html_escape::encode_double_quoted_attribute(page.description);
+    let keywords = page.keywords.join(",");
+    let url = page_url(site_url, Some(page));
+
+    let mut html = replace_element(template, "<title>", "</title>", &title);
+    for (attr, value) in [
+        ("name=\"title\"", &*title),
+        ("name=\"description\"", &*description),
+        ("name=\"keywords\"", &keywords),
+        ("property=\"og:title\"", &*title),
+        ("property=\"og:description\"", &*description),
+        ("property=\"og:url\"", &url),
+        ("property=\"twitter:title\"", &*title),
+        ("property=\"twitter:description\"", &*description),
+        ("property=\"twitter:url\"", &url),
+    ] {
+        html = replace_attr(&html, &format!("<meta {attr} content=\""), value);
+    }
+    replace_attr(&html, "<link rel=\"canonical\" href=\"", &url)
+}
+
+fn replace_element(html: &str, open: &str, close: &str, value: &str) -> String {
+    match html.find(open).and_then(|start| {
+        let from = start + open.len();
+        html[from..].find(close).map(|end| (from, from + end))
+    }) {
+        Some((from, to)) => format!("{}{}{}", &html[..from], value, &html[to..]),
+        None => html.to_string(),
+    }
+}
+
+/// Replaces the attribute value that follows every occurrence of `prefix`.
+fn replace_attr(html: &str, prefix: &str, value: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(prefix) {
+        let from = start + prefix.len();
+        let Some(end) = rest[from..].find('"') else {
+            break;
+        };
+        out.push_str(&rest[..from]);
+        out.push_str(value);
+        rest = &rest[from + end..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,259 @@
+//! Byte buffers as the encoder pages show and accept them: UTF-8 text when
+//! the bytes are printable, otherwise hex.
+
+use super::ToolError;
+
+/// The bytes as text, if they are UTF-8 without control characters other
+/// than tab and line breaks.
+pub fn as_text(bytes: &[u8]) -> Option<&str> {
+    let text = std::str::from_utf8(bytes).ok()?;
+    text.chars()
+        .all(|c| !c.is_control() || matches!(c, '\t' | '\n' | '\r'))
+        .then_some(text)
+}
+
+/// Lowercase hex with a space between bytes.
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 3);
+    for (i, b) in bytes.iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        out.push_str(&format!("{:02x}", b));
+    }
+    out
+}
+
+/// Parses hex digits, ignoring whitespace, `0x` prefixes and `:`, `-` or `,`
+/// separators between bytes.
+pub fn parse_hex(input: &str) -> Result<Vec<u8>, ToolError> {
+    let mut digits = Vec::new();
+    for token in input.split(|c: char| c.is_whitespace() || matches!(c, ':' | '-' | ',')) {
+        let token = token
+            .strip_prefix("0x")
+            .or_else(|| token.strip_prefix("0X"))
+            .unwrap_or(token);
+        for c in token.chars() {
+            let digit = c.to_digit(16).ok_or_else(|| {
+                ToolError::InvalidHex(format!("'{}' at digit {}", c, digits.len() + 1))
+            })?;
+            digits.push(digit as u8);
+        }
+    }
+    if digits.len() % 2 != 0 {
+        return Err(ToolError::InvalidHex("odd number of digits".into()));
+    }
+    Ok(digits
+        .chunks(2)
+        .map(|pair| pair[0] << 4 | pair[1])
+        .collect())
+}
+
+/// Bytes per line and per space-separated group of a hex dump.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DumpLayout {
+    pub width: usize,
+    pub group: usize,
+}
+
+impl Default for DumpLayout {
+    fn default() -> Self {
+        Self {
+            width: 16,
+            group: 2,
+        }
+    }
+}
+
+/// The ASCII column's character for `b`: itself when printable, else `.`.
+pub fn dump_char(b: u8) -> char {
+    if b.is_ascii_graphic() || b == b' ' {
+        b as char
+    } else {
+        '.'
+    }
+}
+
+/// `xxd`-style dump: offset, sixteen bytes in groups of two, then the
+/// printable ASCII with `.` for everything else.
+pub fn hex_dump(bytes: &[u8]) -> String {
+    hex_dump_with(bytes, DumpLayout::default())
+}
+
+pub fn hex_dump_with(bytes: &[u8], layout: DumpLayout) -> String {
+    let width = layout.width.max(1);
+    let group = layout.group.max(1);
+    let mut out = String::new();
+    for (line, chunk) in bytes.chunks(width).enumerate() {
+        out.push_str(&format!("{:08x}:", line * width));
+        for i in 0..width {
+            if i % group == 0 {
+                out.push(' ');
+            }
+            match chunk.get(i) {
+                Some(b) => out.push_str(&format!("{:02x}", b)),
+                None => out.push_str("  "),
+            }
+        }
+        out.push_str("  ");
+        out.extend(chunk.iter().map(|&b| dump_char(b)));
+        out.push('\n');
+    }
+    out
+}
+
+/// Formats `parse_dump` recognizes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DumpFormat {
+    /// `xxd` and `hex_dump`: `00000000: 4865 6c6c  He..`.
+    Xxd,
+    /// `od -x`: octal offsets and little-endian 16-bit words.
+    Od,
+    /// `{ 0x48, 0x65 }`, as from `xxd -i`.
+    CArray,
+    Hex,
+}
+
+impl DumpFormat {
+    /// Catalog id of the format's name.
+    pub fn name_id(self) -> &'static str {
+        match self {
+            DumpFormat::Xxd => "hexdump.format.xxd",
+            DumpFormat::Od => "hexdump.format.od",
+            DumpFormat::CArray => "hexdump.format.c-array",
+            DumpFormat::Hex => "hexdump.format.hex",
+        }
+    }
+
+    /// An `xxd` offset is checked first, since the text column of an `xxd`
+    /// dump may hold anything, braces and `0x` included.
+    fn detect(input: &str) -> Self {
+        let first = input.lines().map(str::trim).find(|l| !l.is_empty());
+        let first_token = first.and_then(|l| l.split_whitespace().next());
+        if first.is_some_and(is_xxd_line) {
+            DumpFormat::Xxd
+        } else if input.contains('{') || (input.contains("0x") && input.contains(',')) {
+            DumpFormat::CArray
+        } else if first_token
+            .is_some_and(|t| t.len() >= 7 && t.bytes().all(|b| (b'0'..=b'7').contains(&b)))
+            && first.is_some_and(|l| {
+                let mut words = l.split_whitespace().skip(1).peekable();
+                words.peek().is_some() && words.all(|w| w.len() == 4)
+            })
+        {
+            DumpFormat::Od
+        } else {
+            DumpFormat::Hex
+        }
+    }
+}
+
+/// Starts with a hex offset followed by `: ` or ends right after the colon.
+fn is_xxd_line(line: &str) -> bool {
+    line.split_once(':').is_some_and(|(offset, rest)| {
+        !offset.is_empty()
+            && offset.bytes().all(|b| b.is_ascii_hexdigit())
+            && (rest.is_empty() || rest.starts_with(' '))
+    })
+}
+
+/// Turns a dump back into bytes, recognizing the format from its first line.
+pub fn parse_dump(input: &str) -> Result<(Vec<u8>, DumpFormat), ToolError> {
+    let format = DumpFormat::detect(input);
+    let bytes = match format {
+        DumpFormat::Xxd => parse_xxd(input)?,
+        DumpFormat::Od => parse_od(input)?,
+        DumpFormat::CArray => parse_c_array(input)?,
+        DumpFormat::Hex => parse_hex(input)?,
+    };
+    Ok((bytes, format))
+}
+
+fn line_error(line: usize, e: ToolError) -> ToolError {
+    match e {
+        ToolError::InvalidHex(message) => {
+            ToolError::InvalidHex(format!("line {}: {}", line + 1, message))
+        }
+        e => e,
+    }
+}
+
+/// The hex column ends at the two spaces before the ASCII column.
+fn parse_xxd(input: &str) -> Result<Vec<u8>, ToolError> {
+    let mut out = Vec::new();
+    for (n, line) in input.lines().enumerate() {
+        let Some((_, rest)) = line.split_once(':') else {
+            continue;
+        };
+        let hex = rest.strip_prefix(' ').unwrap_or(rest);
+        let hex = hex.split("  ").next().unwrap_or_default();
+        out.extend(parse_hex(hex).map_err(|e| line_error(n, e))?);
+    }
+    Ok(out)
+}
+
+/// `*` repeats the previous line up to the next offset, and the last line's
+/// offset alone gives the length, dropping the padding of an odd final byte.
+fn parse_od(input: &str) -> Result<Vec<u8>, ToolError> {
+    let mut out: Vec<u8> = Vec::new();
+    let mut previous: Vec<u8> = Vec::new();
+    let mut repeat = false;
+    let mut length = None;
+    for (n, line) in input.lines().enumerate() {
+        let mut tokens = line.split_whitespace();
+        let Some(offset) = tokens.next() else {
+            continue;
+        };
+        if offset == "*" {
+            repeat = true;
+            continue;
+        }
+        let offset = usize::from_str_radix(offset, 8)
+            .map_err(|_| ToolError::InvalidHex(format!("line {}: offset '{}'", n + 1, offset)))?;
+        if repeat && !previous.is_empty() {
+            while out.len() < offset {
+                out.extend_from_slice(&previous);
+            }
+            out.truncate(offset);
+            repeat = false;
+        }
+        let mut words = Vec::new();
+        for token in tokens {
+            let word = u16::from_str_radix(token, 16)
+                .map_err(|_| ToolError::InvalidHex(format!("line {}: '{}'", n + 1, token)))?;
+            words.extend_from_slice(&word.to_le_bytes());
+        }
+        length = words.is_empty().then_some(offset);
+        if !words.is_empty() {
+            out.extend_from_slice(&words);
+            previous = words;
+        }
+    }
+    if let Some(length) = length {
+        out.truncate(length);
+    }
+    Ok(out)
+}
+
+/// Elements between the braces, or the whole input without them; `0x`
+/// elements are hex and the rest decimal, as in C.
+fn parse_c_array(input: &str) -> Result<Vec<u8>, ToolError> {
+    let body = match (input.find('{'), input.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &input[start + 1..end],
+        _ => input,
+    };
+    body.split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            let value = match token
+                .strip_prefix("0x")
+                .or_else(|| token.strip_prefix("0X"))
+            {
+                Some(hex) => u8::from_str_radix(hex, 16),
+                None => token.parse(),
+            };
+            value.map_err(|_| ToolError::InvalidHex(format!("'{}'", token)))
+        })
+        .collect()
+}
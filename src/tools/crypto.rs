@@ -0,0 +1,333 @@
+use super::{text, Lang, OptionKind, OptionSpec, Options, Output, Tool, ToolError};
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+
+// ==================== Hash ====================
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha256,
+    Sha512,
+    Sha3_256,
+}
+
+impl HashAlgorithm {
+    pub const ALL: [HashAlgorithm; 5] = [
+        HashAlgorithm::Md5,
+        HashAlgorithm::Sha1,
+        HashAlgorithm::Sha256,
+        HashAlgorithm::Sha512,
+        HashAlgorithm::Sha3_256,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            HashAlgorithm::Md5 => "md5",
+            HashAlgorithm::Sha1 => "sha1",
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha512 => "sha512",
+            HashAlgorithm::Sha3_256 => "sha3-256",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HashAlgorithm::Md5 => "MD5",
+            HashAlgorithm::Sha1 => "SHA1",
+            HashAlgorithm::Sha256 => "SHA256",
+            HashAlgorithm::Sha512 => "SHA512",
+            HashAlgorithm::Sha3_256 => "SHA3-256",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.id() == id)
+    }
+
+    pub fn digest(self, data: &[u8]) -> String {
+        use sha2::Digest;
+        match self {
+            HashAlgorithm::Md5 => hex::encode(md5::Md5::digest(data)),
+            HashAlgorithm::Sha1 => hex::encode(sha1::Sha1::digest(data)),
+            HashAlgorithm::Sha256 => hex::encode(sha2::Sha256::digest(data)),
+            HashAlgorithm::Sha512 => hex::encode(sha2::Sha512::digest(data)),
+            HashAlgorithm::Sha3_256 => hex::encode(sha3::Sha3_256::digest(data)),
+        }
+    }
+}
+
+/// Every `HashAlgorithm` at once over data fed in pieces.
+#[derive(Clone, Default)]
+pub struct MultiHasher {
+    md5: md5::Md5,
+    sha1: sha1::Sha1,
+    sha256: sha2::Sha256,
+    sha512: sha2::Sha512,
+    sha3_256: sha3::Sha3_256,
+}
+
+impl MultiHasher {
+    pub fn update(&mut self, data: &[u8]) {
+        use sha2::Digest;
+        self.md5.update(data);
+        self.sha1.update(data);
+        self.sha256.update(data);
+        self.sha512.update(data);
+        self.sha3_256.update(data);
+    }
+
+    /// Digests in `HashAlgorithm::ALL` order.
+    pub fn finalize(self) -> Vec<(HashAlgorithm, String)> {
+        use sha2::Digest;
+        vec![
+            (HashAlgorithm::Md5, hex::encode(self.md5.finalize())),
+            (HashAlgorithm::Sha1, hex::encode(self.sha1.finalize())),
+            (HashAlgorithm::Sha256, hex::encode(self.sha256.finalize())),
+            (HashAlgorithm::Sha512, hex::encode(self.sha512.finalize())),
+            (
+                HashAlgorithm::Sha3_256,
+                hex::encode(self.sha3_256.finalize()),
+            ),
+        ]
+    }
+}
+
+pub fn compute_hashes(data: &[u8]) -> Vec<(HashAlgorithm, String)> {
+    HashAlgorithm::ALL
+        .into_iter()
+        .map(|alg| (alg, alg.digest(data)))
+        .collect()
+}
+
+const HASH_ALG: OptionSpec = OptionSpec {
+    key: "alg",
+    kind: OptionKind::Choice(&["all", "md5", "sha1", "sha256", "sha512", "sha3-256"]),
+    default: "all",
+};
+
+pub struct HashTool;
+
+impl Tool for HashTool {
+    fn id(&self) -> &'static str {
+        "hash"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[HASH_ALG]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        match HashAlgorithm::from_id(options.value(&HASH_ALG)?) {
+            Some(alg) => Ok(Output::Text(alg.digest(input))),
+            None => Ok(Output::Fields(
+                compute_hashes(input)
+                    .into_iter()
+                    .map(|(alg, digest)| (alg.label().to_string(), digest))
+                    .collect(),
+            )),
+        }
+    }
+}
+
+// ==================== AES ====================
+const AES_NONCE: &[u8; 12] = b"unique nonce";
+
+pub fn aes_generate_key() -> String {
+    use rand::Rng;
+    let random_key: [u8; 32] = rand::thread_rng().gen();
+    hex::encode(random_key)
+}
+
+fn aes_cipher(key_hex: &str) -> Result<aes_gcm::Aes256Gcm, ToolError> {
+    use aes_gcm::KeyInit;
+    match hex::decode(key_hex.trim()) {
+        Ok(b) if b.len() == 32 => {
+            aes_gcm::Aes256Gcm::new_from_slice(&b).map_err(|_| ToolError::InvalidKey)
+        }
+        _ => Err(ToolError::InvalidKey),
+    }
+}
+
+pub fn aes_encrypt(key_hex: &str, plaintext: &[u8]) -> Result<String, ToolError> {
+    use aes_gcm::{aead::Aead, Nonce};
+    aes_cipher(key_hex)?
+        .encrypt(Nonce::from_slice(AES_NONCE), plaintext)
+        .map(hex::encode)
+        .map_err(|e| ToolError::Crypto(e.to_string()))
+}
+
+pub fn aes_decrypt(key_hex: &str, ciphertext_hex: &str) -> Result<Vec<u8>, ToolError> {
+    use aes_gcm::{aead::Aead, Nonce};
+    let cipher = aes_cipher(key_hex)?;
+    let encrypted =
+        hex::decode(ciphertext_hex.trim()).map_err(|e| ToolError::InvalidHex(e.to_string()))?;
+    cipher
+        .decrypt(Nonce::from_slice(AES_NONCE), encrypted.as_slice())
+        .map_err(|e| ToolError::Crypto(e.to_string()))
+}
+
+const AES_MODE: OptionSpec = OptionSpec {
+    key: "mode",
+    kind: OptionKind::Choice(&["encrypt", "decrypt"]),
+    default: "encrypt",
+};
+
+const AES_KEY: OptionSpec = OptionSpec {
+    key: "key",
+    kind: OptionKind::Secret,
+    default: "",
+};
+
+pub struct AesTool;
+
+impl Tool for AesTool {
+    fn id(&self) -> &'static str {
+        "aes"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[AES_MODE, AES_KEY]
+    }
+
+    fn sensitive(&self) -> bool {
+        true
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let key = options.value(&AES_KEY)?;
+        match options.value(&AES_MODE)? {
+            "decrypt" => aes_decrypt(key, text(input)?).map(Output::Bytes),
+            _ => aes_encrypt(key, input).map(Output::Text),
+        }
+    }
+}
+
+// ==================== JWT ====================
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SignatureStatus {
+    Valid,
+    Invalid,
+}
+
+impl SignatureStatus {
+    pub fn localized(self, lang: Lang) -> &'static str {
+        lang.t(match self {
+            SignatureStatus::Valid => "jwt.signature-valid",
+            SignatureStatus::Invalid => "jwt.signature-invalid",
+        })
+    }
+}
+
+impl std::fmt::Display for SignatureStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.localized(Lang::En))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedJwt {
+    pub header: String,
+    pub payload: Option<String>,
+    pub signature: Option<SignatureStatus>,
+}
+
+fn jwt_decode_part(part: &str) -> Result<String, String> {
+    let bytes = general_purpose::URL_SAFE_NO_PAD
+        .decode(part)
+        .map_err(|e| format!("Base64 Error: {}", e))?;
+    let json_str = String::from_utf8(bytes).map_err(|e| format!("UTF-8 Error: {}", e))?;
+    let val: serde_json::Value =
+        serde_json::from_str(&json_str).map_err(|e| format!("JSON Error: {}", e))?;
+    Ok(serde_json::to_string_pretty(&val).unwrap())
+}
+
+pub fn jwt_sign(message: &str, secret: &str) -> String {
+    use hmac::{Hmac, Mac};
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(message.as_bytes());
+    general_purpose::URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes())
+}
+
+/// Decodes header and payload; the HS256 signature is only checked when a
+/// secret is given.
+pub fn jwt_decode(token: &str, secret: &str) -> Result<DecodedJwt, ToolError> {
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() < 2 {
+        return Err(ToolError::InvalidJwt);
+    }
+
+    let header = jwt_decode_part(parts[0]).map_err(ToolError::JwtHeader)?;
+    let payload = jwt_decode_part(parts[1]).ok();
+    let signature = (parts.len() >= 3 && !secret.is_empty()).then(|| {
+        let expected = jwt_sign(&format!("{}.{}", parts[0], parts[1]), secret);
+        if expected == parts[2] {
+            SignatureStatus::Valid
+        } else {
+            SignatureStatus::Invalid
+        }
+    });
+
+    Ok(DecodedJwt {
+        header,
+        payload,
+        signature,
+    })
+}
+
+/// Signs a JSON payload as an HS256 token.
+pub fn jwt_encode(payload: &str, secret: &str) -> Result<String, ToolError> {
+    let payload = super::development::json_format(payload, true)?;
+    let message = format!(
+        "{}.{}",
+        general_purpose::URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256","typ":"JWT"}"#),
+        general_purpose::URL_SAFE_NO_PAD.encode(payload)
+    );
+    let signature = jwt_sign(&message, secret);
+    Ok(format!("{}.{}", message, signature))
+}
+
+const JWT_MODE: OptionSpec = OptionSpec {
+    key: "mode",
+    kind: OptionKind::Choice(&["decode", "sign"]),
+    default: "decode",
+};
+
+const JWT_SECRET: OptionSpec = OptionSpec {
+    key: "secret",
+    kind: OptionKind::Secret,
+    default: "",
+};
+
+pub struct JwtTool;
+
+impl Tool for JwtTool {
+    fn id(&self) -> &'static str {
+        "jwt"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[JWT_MODE, JWT_SECRET]
+    }
+
+    fn sensitive(&self) -> bool {
+        true
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let secret = options.value(&JWT_SECRET)?;
+        if options.value(&JWT_MODE)? == "sign" {
+            return jwt_encode(text(input)?, secret).map(Output::Text);
+        }
+        let decoded = jwt_decode(text(input)?.trim(), secret)?;
+        let mut fields = vec![("Header".to_string(), decoded.header)];
+        if let Some(payload) = decoded.payload {
+            fields.push(("Payload".to_string(), payload));
+        }
+        if let Some(signature) = decoded.signature {
+            fields.push(("Signature".to_string(), signature.to_string()));
+        }
+        Ok(Output::Fields(fields))
+    }
+}
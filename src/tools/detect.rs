@@ -0,0 +1,280 @@
+//! Guesses what a pasted blob is by running it through the decoders the tool
+//! pages use, for the Magic page.
+
+use super::bytes;
+use super::crypto;
+use super::development;
+use super::encoding;
+use super::permalink::State;
+use chrono::{TimeZone, Utc};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Jwt,
+    Uuid,
+    Json,
+    Timestamp,
+    UrlEncoded,
+    Hex,
+    Base64,
+    Base58,
+    Base32,
+}
+
+impl Kind {
+    /// Registry slug of the page that handles this kind.
+    pub fn slug(self) -> &'static str {
+        match self {
+            Kind::Jwt => "jwt",
+            Kind::Uuid => "uuid",
+            Kind::Json => "json",
+            Kind::Timestamp => "timestamp",
+            Kind::UrlEncoded => "url-escape",
+            Kind::Hex => "base-conv",
+            Kind::Base64 => "base64",
+            Kind::Base58 => "base58",
+            Kind::Base32 => "base32",
+        }
+    }
+
+    /// Catalog id of the kind's name.
+    pub fn name_id(self) -> &'static str {
+        match self {
+            Kind::Jwt => "magic.kind.jwt",
+            Kind::Uuid => "magic.kind.uuid",
+            Kind::Json => "magic.kind.json",
+            Kind::Timestamp => "magic.kind.timestamp",
+            Kind::UrlEncoded => "magic.kind.url-encoded",
+            Kind::Hex => "magic.kind.hex",
+            Kind::Base64 => "magic.kind.base64",
+            Kind::Base58 => "magic.kind.base58",
+            Kind::Base32 => "magic.kind.base32",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate {
+    pub kind: Kind,
+    /// 1 to 100; higher is more likely.
+    pub confidence: u8,
+    /// The decoded value: text when printable, hex otherwise.
+    pub preview: String,
+    /// Permalink state that opens the matching page with the input filled in.
+    pub state: State,
+}
+
+fn state(fields: &[(&str, String)]) -> State {
+    fields
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect()
+}
+
+/// State of the Base64/32/58 pages: the encoded text and the decoded bytes.
+fn byte_page_state(encoded: &str, decoded: &[u8]) -> State {
+    match bytes::as_text(decoded) {
+        Some(text) => state(&[("output", encoded.into()), ("input", text.into())]),
+        None => state(&[
+            ("output", encoded.into()),
+            ("input-hex", bytes::to_hex(decoded)),
+        ]),
+    }
+}
+
+fn preview(decoded: &[u8]) -> String {
+    bytes::as_text(decoded)
+        .map(str::to_string)
+        .unwrap_or_else(|| bytes::to_hex(decoded))
+}
+
+/// Candidates for `input`, most likely first. Empty when nothing fits.
+pub fn detect(input: &str) -> Vec<Candidate> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Vec::new();
+    }
+    let mut found: Vec<Candidate> = [
+        jwt,
+        uuid,
+        json,
+        timestamp,
+        url_encoded,
+        hex,
+        base64,
+        base58,
+        base32,
+    ]
+    .iter()
+    .filter_map(|check| check(input))
+    .collect();
+    // Stable, so equal scores keep the order above.
+    found.sort_by_key(|c| std::cmp::Reverse(c.confidence));
+    found
+}
+
+fn jwt(input: &str) -> Option<Candidate> {
+    if input.split('.').count() != 3 {
+        return None;
+    }
+    let decoded = crypto::jwt_decode(input, "").ok()?;
+    Some(Candidate {
+        kind: Kind::Jwt,
+        confidence: 98,
+        preview: decoded.payload.unwrap_or(decoded.header),
+        state: state(&[("input", input.into())]),
+    })
+}
+
+fn uuid(input: &str) -> Option<Candidate> {
+    let uuid = uuid::Uuid::parse_str(input).ok()?;
+    // 32 bare hex digits are more often a digest or a key.
+    let confidence = if input.contains('-') { 97 } else { 45 };
+    let preview = match uuid.get_version_num() {
+        0 => uuid.hyphenated().to_string(),
+        version => format!("v{version} · {}", uuid.hyphenated()),
+    };
+    Some(Candidate {
+        kind: Kind::Uuid,
+        confidence,
+        preview,
+        state: State::new(),
+    })
+}
+
+fn json(input: &str) -> Option<Candidate> {
+    if !(input.starts_with('{') || input.starts_with('[')) {
+        return None;
+    }
+    let formatted = development::json_format(input, false).ok()?;
+    Some(Candidate {
+        kind: Kind::Json,
+        confidence: 95,
+        preview: formatted,
+        state: state(&[("input", input.into())]),
+    })
+}
+
+/// Seconds or milliseconds since the epoch, between 1990 and 2100.
+fn timestamp(input: &str) -> Option<Candidate> {
+    if !input.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let value: i64 = input.parse().ok()?;
+    let (seconds, millis) = match input.len() {
+        9 | 10 => (value, 0),
+        12 | 13 => (value / 1000, value % 1000),
+        _ => return None,
+    };
+    let from = Utc
+        .with_ymd_and_hms(1990, 1, 1, 0, 0, 0)
+        .unwrap()
+        .timestamp();
+    let to = Utc
+        .with_ymd_and_hms(2100, 1, 1, 0, 0, 0)
+        .unwrap()
+        .timestamp();
+    if !(from..to).contains(&seconds) {
+        return None;
+    }
+    let time = Utc.timestamp_millis_opt(seconds * 1000 + millis).single()?;
+    Some(Candidate {
+        kind: Kind::Timestamp,
+        confidence: 85,
+        preview: time.to_rfc3339(),
+        state: state(&[("input", seconds.to_string())]),
+    })
+}
+
+fn url_encoded(input: &str) -> Option<Candidate> {
+    let escapes = input.match_indices('%').filter(|(i, _)| {
+        input
+            .get(i + 1..i + 3)
+            .is_some_and(|h| h.bytes().all(|b| b.is_ascii_hexdigit()))
+    });
+    if escapes.count() == 0 {
+        return None;
+    }
+    let decoded = encoding::url_decode(input).ok()?;
+    Some(Candidate {
+        kind: Kind::UrlEncoded,
+        confidence: 80,
+        state: state(&[("output", input.into()), ("input", decoded.clone())]),
+        preview: decoded,
+    })
+}
+
+fn hex(input: &str) -> Option<Candidate> {
+    let digits = input.strip_prefix("0x").unwrap_or(input);
+    if digits.len() < 2 || !digits.len().is_multiple_of(2) {
+        return None;
+    }
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let decoded = bytes::parse_hex(digits).ok()?;
+    // All decimal digits is more likely a number.
+    let confidence = if digits.bytes().any(|b| b.is_ascii_alphabetic()) {
+        75
+    } else {
+        30
+    };
+    Some(Candidate {
+        kind: Kind::Hex,
+        confidence,
+        preview: preview(&decoded),
+        state: state(&[("input", digits.into()), ("from", "16".into())]),
+    })
+}
+
+/// Extra confidence for decoders that produce readable text, since almost
+/// any short alphanumeric string decodes to some bytes.
+fn text_bonus(decoded: &[u8]) -> u8 {
+    if bytes::as_text(decoded).is_some() {
+        25
+    } else {
+        0
+    }
+}
+
+fn base64(input: &str) -> Option<Candidate> {
+    if input.len() < 4 || !input.len().is_multiple_of(4) {
+        return None;
+    }
+    let decoded = encoding::base64_decode(input).ok()?;
+    let marked = input.ends_with('=') || input.contains(['+', '/']);
+    let confidence = 50 + text_bonus(&decoded) + if marked { 15 } else { 0 };
+    Some(Candidate {
+        kind: Kind::Base64,
+        confidence,
+        preview: preview(&decoded),
+        state: byte_page_state(input, &decoded),
+    })
+}
+
+fn base58(input: &str) -> Option<Candidate> {
+    if input.len() < 4 {
+        return None;
+    }
+    let decoded = encoding::base58_decode(input).ok()?;
+    Some(Candidate {
+        kind: Kind::Base58,
+        confidence: 40 + text_bonus(&decoded),
+        preview: preview(&decoded),
+        state: byte_page_state(input, &decoded),
+    })
+}
+
+fn base32(input: &str) -> Option<Candidate> {
+    if input.len() < 4 {
+        return None;
+    }
+    let decoded = encoding::base32_decode(input).ok()?;
+    Some(Candidate {
+        kind: Kind::Base32,
+        confidence: 35 + text_bonus(&decoded),
+        preview: preview(&decoded),
+        state: byte_page_state(input, &decoded),
+    })
+}
@@ -0,0 +1,284 @@
+use super::bytes::{self, DumpLayout};
+use super::{text, OptionKind, OptionSpec, Options, Output, Tool, ToolError, MODE_ENCODE_DECODE};
+use serde::{Deserialize, Serialize};
+
+// ==================== JSON ====================
+pub fn json_format(input: &str, minify: bool) -> Result<String, ToolError> {
+    let v = serde_json::from_str::<serde_json::Value>(input)
+        .map_err(|e| ToolError::Json(e.to_string()))?;
+    Ok(if minify {
+        serde_json::to_string(&v).unwrap()
+    } else {
+        serde_json::to_string_pretty(&v).unwrap()
+    })
+}
+
+const JSON_STYLE: OptionSpec = OptionSpec {
+    key: "style",
+    kind: OptionKind::Choice(&["pretty", "minify"]),
+    default: "pretty",
+};
+
+pub struct JsonTool;
+
+impl Tool for JsonTool {
+    fn id(&self) -> &'static str {
+        "json"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[JSON_STYLE]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let minify = options.value(&JSON_STYLE)? == "minify";
+        json_format(text(input)?, minify).map(Output::Text)
+    }
+}
+
+// ==================== Regex ====================
+pub fn regex_find(pattern: &str, text: &str) -> Result<Vec<String>, ToolError> {
+    let re = regex::Regex::new(pattern).map_err(|e| ToolError::Regex(e.to_string()))?;
+    Ok(re.find_iter(text).map(|m| m.as_str().to_string()).collect())
+}
+
+pub fn regex_summary(matches: &[String]) -> String {
+    if matches.is_empty() {
+        "No match".into()
+    } else {
+        matches.join(", ")
+    }
+}
+
+const REGEX_PATTERN: OptionSpec = OptionSpec {
+    key: "pattern",
+    kind: OptionKind::Text,
+    default: "",
+};
+
+pub struct RegexTool;
+
+impl Tool for RegexTool {
+    fn id(&self) -> &'static str {
+        "regex"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[REGEX_PATTERN]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let matches = regex_find(options.value(&REGEX_PATTERN)?, text(input)?)?;
+        Ok(Output::Text(regex_summary(&matches)))
+    }
+}
+
+// ==================== Diff ====================
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DiffTag {
+    Delete,
+    Insert,
+    Equal,
+}
+
+impl DiffTag {
+    pub fn sign(self) -> &'static str {
+        match self {
+            DiffTag::Delete => "-",
+            DiffTag::Insert => "+",
+            DiffTag::Equal => " ",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DiffLine {
+    pub tag: DiffTag,
+    pub value: String,
+}
+
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    use similar::{ChangeTag, TextDiff};
+
+    TextDiff::from_lines(old, new)
+        .iter_all_changes()
+        .map(|change| DiffLine {
+            tag: match change.tag() {
+                ChangeTag::Delete => DiffTag::Delete,
+                ChangeTag::Insert => DiffTag::Insert,
+                ChangeTag::Equal => DiffTag::Equal,
+            },
+            value: change.value().to_string(),
+        })
+        .collect()
+}
+
+const DIFF_ORIGINAL: OptionSpec = OptionSpec {
+    key: "original",
+    kind: OptionKind::Text,
+    default: "",
+};
+
+pub struct DiffTool;
+
+impl Tool for DiffTool {
+    fn id(&self) -> &'static str {
+        "diff"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[DIFF_ORIGINAL]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let lines = diff_lines(options.value(&DIFF_ORIGINAL)?, text(input)?);
+        Ok(Output::Text(
+            lines
+                .iter()
+                .map(|line| format!("{}{}", line.tag.sign(), line.value))
+                .collect(),
+        ))
+    }
+}
+
+// ==================== UUID ====================
+pub fn generate_uuid() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+pub struct UuidTool;
+
+impl Tool for UuidTool {
+    fn id(&self) -> &'static str {
+        "uuid"
+    }
+
+    fn run(&self, _input: &[u8], _options: &Options) -> Result<Output, ToolError> {
+        Ok(Output::Text(generate_uuid()))
+    }
+}
+
+// ==================== Timestamp ====================
+pub fn now_timestamp() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+pub fn timestamp_to_iso(input: &str) -> Result<String, ToolError> {
+    use chrono::{TimeZone, Utc};
+
+    let val = input
+        .trim()
+        .parse::<i64>()
+        .map_err(|_| ToolError::InvalidTimestamp)?;
+    Utc.timestamp_opt(val, 0)
+        .single()
+        .map(|dt| dt.to_rfc3339())
+        .ok_or(ToolError::InvalidTimestamp)
+}
+
+pub struct TimestampTool;
+
+impl Tool for TimestampTool {
+    fn id(&self) -> &'static str {
+        "timestamp"
+    }
+
+    fn run(&self, input: &[u8], _options: &Options) -> Result<Output, ToolError> {
+        timestamp_to_iso(text(input)?).map(Output::Text)
+    }
+}
+
+// ==================== Base Conv ====================
+pub const BASES: [(u32, &str); 4] = [(10, "Decimal"), (16, "Hex"), (2, "Binary"), (8, "Octal")];
+
+pub fn base_convert(value: &str, from: u32, to: u32) -> Result<String, ToolError> {
+    u128::from_str_radix(value, from)
+        .map_err(|_| ToolError::InvalidNumber)
+        .and_then(|n| match to {
+            2 => Ok(format!("{:b}", n)),
+            8 => Ok(format!("{:o}", n)),
+            10 => Ok(format!("{}", n)),
+            16 => Ok(format!("{:x}", n)),
+            _ => Err(ToolError::InvalidNumber),
+        })
+}
+
+const BASE_FROM: OptionSpec = OptionSpec {
+    key: "from",
+    kind: OptionKind::Choice(&["10", "16", "2", "8"]),
+    default: "10",
+};
+
+const BASE_TO: OptionSpec = OptionSpec {
+    key: "to",
+    kind: OptionKind::Choice(&["all", "10", "16", "2", "8"]),
+    default: "all",
+};
+
+pub struct BaseConvTool;
+
+impl Tool for BaseConvTool {
+    fn id(&self) -> &'static str {
+        "base-conv"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[BASE_FROM, BASE_TO]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let value = text(input)?.trim();
+        let from = options.value(&BASE_FROM)?.parse().unwrap();
+        match options.value(&BASE_TO)?.parse::<u32>() {
+            Ok(to) => base_convert(value, from, to).map(Output::Text),
+            Err(_) => BASES
+                .iter()
+                .map(|&(base, label)| Ok((label.to_string(), base_convert(value, from, base)?)))
+                .collect::<Result<_, _>>()
+                .map(Output::Fields),
+        }
+    }
+}
+
+// ==================== Hex Dump ====================
+pub const HEXDUMP_WIDTH: OptionSpec = OptionSpec {
+    key: "width",
+    kind: OptionKind::Choice(&["8", "16", "32"]),
+    default: "16",
+};
+
+pub const HEXDUMP_GROUP: OptionSpec = OptionSpec {
+    key: "group",
+    kind: OptionKind::Choice(&["1", "2", "4", "8"]),
+    default: "2",
+};
+
+pub fn dump_layout(options: &Options) -> Result<DumpLayout, ToolError> {
+    Ok(DumpLayout {
+        width: options.value(&HEXDUMP_WIDTH)?.parse().unwrap(),
+        group: options.value(&HEXDUMP_GROUP)?.parse().unwrap(),
+    })
+}
+
+/// Encoding dumps the bytes; decoding parses a dump back.
+pub struct HexDumpTool;
+
+impl Tool for HexDumpTool {
+    fn id(&self) -> &'static str {
+        "hexdump"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE, HEXDUMP_WIDTH, HEXDUMP_GROUP]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => bytes::parse_dump(text(input)?).map(|(b, _)| Output::Bytes(b)),
+            _ => Ok(Output::Text(bytes::hex_dump_with(
+                input,
+                dump_layout(options)?,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,1026 @@
+use super::{
+    text, Lang, OptionKind, OptionSpec, Options, Output, Tool, ToolError, MODE_ENCODE_DECODE,
+};
+use base64::alphabet;
+use base64::engine::{
+    general_purpose, DecodePaddingMode, Engine as _, GeneralPurpose, GeneralPurposeConfig,
+};
+
+// ==================== Base64 ====================
+pub fn base64_encode(input: &[u8]) -> String {
+    general_purpose::STANDARD.encode(input)
+}
+
+pub fn base64_decode(input: &str) -> Result<Vec<u8>, ToolError> {
+    general_purpose::STANDARD
+        .decode(input.trim())
+        .map_err(|e| ToolError::Decode(e.to_string()))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    Standard,
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    pub fn id(self) -> &'static str {
+        match self {
+            Base64Alphabet::Standard => "standard",
+            Base64Alphabet::UrlSafe => "url-safe",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        [Base64Alphabet::Standard, Base64Alphabet::UrlSafe]
+            .into_iter()
+            .find(|a| a.id() == id)
+    }
+
+    fn engine(self, config: GeneralPurposeConfig) -> GeneralPurpose {
+        match self {
+            Base64Alphabet::Standard => GeneralPurpose::new(&alphabet::STANDARD, config),
+            Base64Alphabet::UrlSafe => GeneralPurpose::new(&alphabet::URL_SAFE, config),
+        }
+    }
+}
+
+/// Writes padding, and on decoding requires it, only when `padding` is set.
+fn padding_config(padding: bool) -> GeneralPurposeConfig {
+    let mode = if padding {
+        DecodePaddingMode::RequireCanonical
+    } else {
+        DecodePaddingMode::RequireNone
+    };
+    GeneralPurposeConfig::new()
+        .with_encode_padding(padding)
+        .with_decode_padding_mode(mode)
+}
+
+/// How Base64 text is written: the alphabet, `=` padding and the line length
+/// (`None` for a single line).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Base64Variant {
+    pub alphabet: Base64Alphabet,
+    pub padding: bool,
+    pub wrap: Option<usize>,
+}
+
+impl Default for Base64Variant {
+    fn default() -> Self {
+        Self {
+            alphabet: Base64Alphabet::Standard,
+            padding: true,
+            wrap: None,
+        }
+    }
+}
+
+impl Base64Variant {
+    /// Reads the `alphabet`, `padding` and `wrap` options.
+    pub fn from_options(options: &Options) -> Result<Self, ToolError> {
+        let wrap = match options.value(&BASE64_WRAP)? {
+            "none" => None,
+            columns => columns.parse().ok(),
+        };
+        Ok(Self {
+            alphabet: Base64Alphabet::from_id(options.value(&BASE64_ALPHABET)?)
+                .unwrap_or(Base64Alphabet::Standard),
+            padding: options.value(&BASE64_PADDING)? == "padded",
+            wrap,
+        })
+    }
+
+    /// Localized summary, e.g. "URL-safe, unpadded".
+    pub fn describe(&self, lang: Lang) -> String {
+        let mut parts = vec![
+            lang.t(match self.alphabet {
+                Base64Alphabet::Standard => "option.alphabet.standard",
+                Base64Alphabet::UrlSafe => "option.alphabet.url-safe",
+            })
+            .to_string(),
+            lang.t(if self.padding {
+                "option.padding.padded"
+            } else {
+                "option.padding.unpadded"
+            })
+            .to_string(),
+        ];
+        if let Some(columns) = self.wrap {
+            parts.push(lang.format("base64.wrapped", &[("columns", &columns.to_string())]));
+        }
+        parts.join(", ")
+    }
+}
+
+pub fn base64_encode_with(input: &[u8], variant: &Base64Variant) -> String {
+    let encoded = variant
+        .alphabet
+        .engine(padding_config(variant.padding))
+        .encode(input);
+    match variant.wrap {
+        Some(columns) if columns > 0 => encoded
+            .as_bytes()
+            .chunks(columns)
+            .map(|line| std::str::from_utf8(line).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => encoded,
+    }
+}
+
+/// Decodes exactly `variant`'s alphabet and padding, on one line.
+pub fn base64_decode_strict(input: &str, variant: &Base64Variant) -> Result<Vec<u8>, ToolError> {
+    variant
+        .alphabet
+        .engine(padding_config(variant.padding))
+        .decode(input.trim())
+        .map_err(|e| ToolError::Decode(e.to_string()))
+}
+
+/// Decodes any variant: whitespace and line breaks are ignored, padding is
+/// optional and the alphabet is told apart by `+/` or `-_`. Also returns the
+/// variant the input was written in.
+pub fn base64_decode_lenient(input: &str) -> Result<(Vec<u8>, Base64Variant), ToolError> {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect();
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+
+    let standard = compact.contains(['+', '/']);
+    let url_safe = compact.contains(['-', '_']);
+    let alphabet = match (standard, url_safe) {
+        (true, true) => {
+            return Err(ToolError::Decode(
+                "mixes standard (+/) and URL-safe (-_) characters".into(),
+            ))
+        }
+        (false, true) => Base64Alphabet::UrlSafe,
+        _ => Base64Alphabet::Standard,
+    };
+    // Wrapped when every line but the last has the same length.
+    let wrap = match lines.split_last() {
+        Some((last, full @ [first, ..])) => {
+            let columns = first.len();
+            (full.iter().all(|l| l.len() == columns) && last.len() <= columns).then_some(columns)
+        }
+        _ => None,
+    };
+    let variant = Base64Variant {
+        alphabet,
+        padding: compact.ends_with('='),
+        wrap,
+    };
+
+    let decoded = alphabet
+        .engine(
+            GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+        )
+        .decode(&compact)
+        .map_err(|e| ToolError::Decode(e.to_string()))?;
+    Ok((decoded, variant))
+}
+
+pub const BASE64_ALPHABET: OptionSpec = OptionSpec {
+    key: "alphabet",
+    kind: OptionKind::Choice(&["standard", "url-safe"]),
+    default: "standard",
+};
+
+pub const BASE64_PADDING: OptionSpec = OptionSpec {
+    key: "padding",
+    kind: OptionKind::Choice(&["padded", "unpadded"]),
+    default: "padded",
+};
+
+pub const BASE64_WRAP: OptionSpec = OptionSpec {
+    key: "wrap",
+    kind: OptionKind::Choice(&["none", "64", "76"]),
+    default: "none",
+};
+
+/// `lenient` decodes any variant; `strict` only the selected one.
+pub const BASE64_DECODER: OptionSpec = OptionSpec {
+    key: "decoder",
+    kind: OptionKind::Choice(&["lenient", "strict"]),
+    default: "lenient",
+};
+
+pub struct Base64Tool;
+
+impl Tool for Base64Tool {
+    fn id(&self) -> &'static str {
+        "base64"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[
+            MODE_ENCODE_DECODE,
+            BASE64_ALPHABET,
+            BASE64_PADDING,
+            BASE64_WRAP,
+            BASE64_DECODER,
+        ]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let variant = Base64Variant::from_options(options)?;
+        match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => match options.value(&BASE64_DECODER)? {
+                "strict" => base64_decode_strict(text(input)?, &variant).map(Output::Bytes),
+                _ => base64_decode_lenient(text(input)?).map(|(bytes, _)| Output::Bytes(bytes)),
+            },
+            _ => Ok(Output::Text(base64_encode_with(input, &variant))),
+        }
+    }
+}
+
+// ==================== Base32 ====================
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base32Alphabet {
+    Rfc4648,
+    Rfc4648NoPad,
+    Hex,
+    Crockford,
+    Z,
+}
+
+impl Base32Alphabet {
+    pub const ALL: [Base32Alphabet; 5] = [
+        Base32Alphabet::Rfc4648,
+        Base32Alphabet::Rfc4648NoPad,
+        Base32Alphabet::Hex,
+        Base32Alphabet::Crockford,
+        Base32Alphabet::Z,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Base32Alphabet::Rfc4648 => "rfc4648",
+            Base32Alphabet::Rfc4648NoPad => "rfc4648-nopad",
+            Base32Alphabet::Hex => "base32hex",
+            Base32Alphabet::Crockford => "crockford",
+            Base32Alphabet::Z => "z-base-32",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.id() == id)
+    }
+
+    pub fn from_options(options: &Options) -> Result<Self, ToolError> {
+        Ok(Self::from_id(options.value(&BASE32_ALPHABET)?).unwrap_or(Base32Alphabet::Rfc4648))
+    }
+
+    fn codec(self) -> base32::Alphabet {
+        match self {
+            Base32Alphabet::Rfc4648 => base32::Alphabet::Rfc4648 { padding: true },
+            Base32Alphabet::Rfc4648NoPad => base32::Alphabet::Rfc4648 { padding: false },
+            Base32Alphabet::Hex => base32::Alphabet::Rfc4648Hex { padding: true },
+            Base32Alphabet::Crockford => base32::Alphabet::Crockford,
+            Base32Alphabet::Z => base32::Alphabet::Z,
+        }
+    }
+
+    /// Symbols accepted when decoding, after `normalize`. Crockford also
+    /// takes I, L and O as 1, 1 and 0.
+    fn symbols(self) -> &'static str {
+        match self {
+            Base32Alphabet::Rfc4648 | Base32Alphabet::Rfc4648NoPad => {
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567"
+            }
+            Base32Alphabet::Hex => "0123456789ABCDEFGHIJKLMNOPQRSTUV",
+            Base32Alphabet::Crockford => "0123456789ABCDEFGHIJKLMNOPQRSTVWXYZ",
+            Base32Alphabet::Z => "ybndrfg8ejkmcpqxot1uwisza345h769",
+        }
+    }
+
+    /// Decoding is case-insensitive.
+    fn normalize(self, c: char) -> char {
+        match self {
+            Base32Alphabet::Z => c.to_ascii_lowercase(),
+            _ => c.to_ascii_uppercase(),
+        }
+    }
+
+    fn padded(self) -> bool {
+        matches!(self, Base32Alphabet::Rfc4648 | Base32Alphabet::Hex)
+    }
+}
+
+/// RFC 4648 with padding.
+pub fn base32_encode(input: &[u8]) -> String {
+    base32_encode_with(input, Base32Alphabet::Rfc4648)
+}
+
+pub fn base32_decode(input: &str) -> Result<Vec<u8>, ToolError> {
+    base32_decode_with(input, Base32Alphabet::Rfc4648)
+}
+
+pub fn base32_encode_with(input: &[u8], alphabet: Base32Alphabet) -> String {
+    base32::encode(alphabet.codec(), input)
+}
+
+/// Ignores whitespace (and Crockford's `-` separators) and case; padding is
+/// optional for the padded alphabets. Reports the first character that is
+/// not in `alphabet`, counting from 1.
+pub fn base32_decode_with(input: &str, alphabet: Base32Alphabet) -> Result<Vec<u8>, ToolError> {
+    let mut data = String::with_capacity(input.len());
+    let mut padding = false;
+    for (i, c) in input.chars().enumerate() {
+        if c.is_whitespace() || (c == '-' && alphabet == Base32Alphabet::Crockford) {
+            continue;
+        }
+        let symbol = alphabet.normalize(c);
+        let valid = if symbol == '=' {
+            alphabet.padded()
+        } else {
+            !padding && alphabet.symbols().contains(symbol)
+        };
+        if !valid {
+            return Err(ToolError::InvalidCharacter {
+                ch: c,
+                position: i + 1,
+            });
+        }
+        padding |= symbol == '=';
+        data.push(symbol);
+    }
+    // 1, 3 or 6 trailing symbols cannot hold whole bytes.
+    if matches!(data.trim_end_matches('=').len() % 8, 1 | 3 | 6) {
+        return Err(ToolError::InvalidBase32);
+    }
+    base32::decode(alphabet.codec(), &data).ok_or(ToolError::InvalidBase32)
+}
+
+pub const BASE32_ALPHABET: OptionSpec = OptionSpec {
+    key: "alphabet",
+    kind: OptionKind::Choice(&[
+        "rfc4648",
+        "rfc4648-nopad",
+        "base32hex",
+        "crockford",
+        "z-base-32",
+    ]),
+    default: "rfc4648",
+};
+
+pub struct Base32Tool;
+
+impl Tool for Base32Tool {
+    fn id(&self) -> &'static str {
+        "base32"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE, BASE32_ALPHABET]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let alphabet = Base32Alphabet::from_options(options)?;
+        match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => base32_decode_with(text(input)?, alphabet).map(Output::Bytes),
+            _ => Ok(Output::Text(base32_encode_with(input, alphabet))),
+        }
+    }
+}
+
+// ==================== Base58 ====================
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base58Alphabet {
+    Bitcoin,
+    Ripple,
+    Flickr,
+}
+
+impl Base58Alphabet {
+    pub const ALL: [Base58Alphabet; 3] = [
+        Base58Alphabet::Bitcoin,
+        Base58Alphabet::Ripple,
+        Base58Alphabet::Flickr,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Base58Alphabet::Bitcoin => "bitcoin",
+            Base58Alphabet::Ripple => "ripple",
+            Base58Alphabet::Flickr => "flickr",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.id() == id)
+    }
+
+    pub fn from_options(options: &Options) -> Result<Self, ToolError> {
+        Ok(Self::from_id(options.value(&BASE58_ALPHABET)?).unwrap_or(Base58Alphabet::Bitcoin))
+    }
+
+    fn codec(self) -> &'static bs58::Alphabet {
+        match self {
+            Base58Alphabet::Bitcoin => bs58::Alphabet::BITCOIN,
+            Base58Alphabet::Ripple => bs58::Alphabet::RIPPLE,
+            Base58Alphabet::Flickr => bs58::Alphabet::FLICKR,
+        }
+    }
+}
+
+/// Bitcoin alphabet.
+pub fn base58_encode(input: &[u8]) -> String {
+    base58_encode_with(input, Base58Alphabet::Bitcoin)
+}
+
+pub fn base58_decode(input: &str) -> Result<Vec<u8>, ToolError> {
+    base58_decode_with(input, Base58Alphabet::Bitcoin)
+}
+
+pub fn base58_encode_with(input: &[u8], alphabet: Base58Alphabet) -> String {
+    bs58::encode(input)
+        .with_alphabet(alphabet.codec())
+        .into_string()
+}
+
+pub fn base58_decode_with(input: &str, alphabet: Base58Alphabet) -> Result<Vec<u8>, ToolError> {
+    let input = input.trim();
+    bs58::decode(input)
+        .with_alphabet(alphabet.codec())
+        .into_vec()
+        .map_err(|e| match e {
+            bs58::decode::Error::InvalidCharacter { index, .. }
+            | bs58::decode::Error::NonAsciiCharacter { index } => ToolError::InvalidCharacter {
+                ch: input[index..].chars().next().unwrap_or_default(),
+                position: input[..index].chars().count() + 1,
+            },
+            e => ToolError::Decode(e.to_string()),
+        })
+}
+
+const CHECKSUM_LEN: usize = 4;
+
+/// First four bytes of the double SHA-256 of `data`.
+fn base58_checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    use sha2::{Digest, Sha256};
+    let digest = Sha256::digest(Sha256::digest(data));
+    let mut checksum = [0; CHECKSUM_LEN];
+    checksum.copy_from_slice(&digest[..CHECKSUM_LEN]);
+    checksum
+}
+
+/// Base58Check: `data` (version byte first, if any) followed by its
+/// checksum.
+pub fn base58check_encode(data: &[u8], alphabet: Base58Alphabet) -> String {
+    let mut bytes = data.to_vec();
+    bytes.extend_from_slice(&base58_checksum(data));
+    base58_encode_with(&bytes, alphabet)
+}
+
+/// Verifies and drops the checksum, leaving the version byte and payload.
+pub fn base58check_decode(input: &str, alphabet: Base58Alphabet) -> Result<Vec<u8>, ToolError> {
+    let mut bytes = base58_decode_with(input, alphabet)?;
+    if bytes.len() < CHECKSUM_LEN {
+        return Err(ToolError::MissingChecksum);
+    }
+    let found = bytes.split_off(bytes.len() - CHECKSUM_LEN);
+    let expected = base58_checksum(&bytes);
+    if found != expected {
+        return Err(ToolError::ChecksumMismatch {
+            expected: hex::encode(expected),
+            found: hex::encode(found),
+        });
+    }
+    Ok(bytes)
+}
+
+pub const BASE58_ALPHABET: OptionSpec = OptionSpec {
+    key: "alphabet",
+    kind: OptionKind::Choice(&["bitcoin", "ripple", "flickr"]),
+    default: "bitcoin",
+};
+
+pub const BASE58_CHECKSUM: OptionSpec = OptionSpec {
+    key: "checksum",
+    kind: OptionKind::Choice(&["none", "base58check"]),
+    default: "none",
+};
+
+pub struct Base58Tool;
+
+impl Tool for Base58Tool {
+    fn id(&self) -> &'static str {
+        "base58"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE, BASE58_ALPHABET, BASE58_CHECKSUM]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let alphabet = Base58Alphabet::from_options(options)?;
+        let check = options.value(&BASE58_CHECKSUM)? == "base58check";
+        match (options.value(&MODE_ENCODE_DECODE)?, check) {
+            ("decode", true) => base58check_decode(text(input)?, alphabet).map(Output::Bytes),
+            ("decode", false) => base58_decode_with(text(input)?, alphabet).map(Output::Bytes),
+            (_, true) => Ok(Output::Text(base58check_encode(input, alphabet))),
+            (_, false) => Ok(Output::Text(base58_encode_with(input, alphabet))),
+        }
+    }
+}
+
+// ==================== Ascii85 ====================
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Base85 {
+    /// Adobe/btoa: `!` to `u`, with `z` for four zero bytes.
+    Ascii85,
+    /// ZeroMQ's Z85.
+    Z85,
+}
+
+const Z85_SYMBOLS: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+impl Base85 {
+    pub fn from_options(options: &Options) -> Result<Self, ToolError> {
+        Ok(match options.value(&ASCII85_VARIANT)? {
+            "z85" => Base85::Z85,
+            _ => Base85::Ascii85,
+        })
+    }
+
+    fn symbol(self, digit: u32) -> char {
+        match self {
+            Base85::Ascii85 => char::from(b'!' + digit as u8),
+            Base85::Z85 => char::from(Z85_SYMBOLS[digit as usize]),
+        }
+    }
+
+    fn digit(self, c: char) -> Option<u32> {
+        match self {
+            Base85::Ascii85 => ('!'..='u').contains(&c).then(|| c as u32 - '!' as u32),
+            Base85::Z85 => Z85_SYMBOLS
+                .iter()
+                .position(|&s| char::from(s) == c)
+                .map(|d| d as u32),
+        }
+    }
+}
+
+/// A final group of `n` bytes becomes `n + 1` characters, so Z85 also takes
+/// lengths that are not a multiple of four.
+pub fn base85_encode(input: &[u8], variant: Base85) -> String {
+    let mut out = String::with_capacity(input.len().div_ceil(4) * 5);
+    for chunk in input.chunks(4) {
+        let mut group = [0u8; 4];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let value = u32::from_be_bytes(group);
+        if value == 0 && chunk.len() == 4 && variant == Base85::Ascii85 {
+            out.push('z');
+            continue;
+        }
+        let mut digits = [0u32; 5];
+        let mut rest = value;
+        for digit in digits.iter_mut().rev() {
+            *digit = rest % 85;
+            rest /= 85;
+        }
+        out.extend(digits[..chunk.len() + 1].iter().map(|&d| variant.symbol(d)));
+    }
+    out
+}
+
+/// Ignores whitespace, and for Ascii85 the `<~` `~>` delimiters.
+pub fn base85_decode(input: &str, variant: Base85) -> Result<Vec<u8>, ToolError> {
+    let (mut start, mut body) = trim_counted(input);
+    if variant == Base85::Ascii85 {
+        if let Some(rest) = body.strip_prefix("<~") {
+            start += 2;
+            body = rest;
+        }
+        body = body.strip_suffix("~>").unwrap_or(body);
+    }
+    let mut out = Vec::with_capacity(body.len() / 5 * 4);
+    // (digit, position) of the group so far.
+    let mut group: Vec<(u32, usize)> = Vec::with_capacity(5);
+    for (i, c) in body.chars().enumerate() {
+        let position = start + i + 1;
+        if c.is_whitespace() {
+            continue;
+        }
+        if c == 'z' && variant == Base85::Ascii85 && group.is_empty() {
+            out.extend_from_slice(&[0; 4]);
+            continue;
+        }
+        let digit = variant
+            .digit(c)
+            .ok_or(ToolError::InvalidCharacter { ch: c, position })?;
+        group.push((digit, position));
+        if group.len() == 5 {
+            out.extend_from_slice(&base85_group(&group)?);
+            group.clear();
+        }
+    }
+    match group.len() {
+        0 => {}
+        1 => return Err(ToolError::InvalidLength),
+        n => {
+            // Pad with the highest digit so the kept bytes round up correctly.
+            let position = group[0].1;
+            group.resize(5, (84, position));
+            out.extend_from_slice(&base85_group(&group)?[..n - 1]);
+        }
+    }
+    Ok(out)
+}
+
+fn base85_group(group: &[(u32, usize)]) -> Result<[u8; 4], ToolError> {
+    let value = group
+        .iter()
+        .fold(0u64, |value, &(digit, _)| value * 85 + u64::from(digit));
+    u32::try_from(value)
+        .map(u32::to_be_bytes)
+        .map_err(|_| ToolError::GroupOverflow {
+            position: group[0].1,
+        })
+}
+
+/// `input` without surrounding whitespace, and how many characters were cut
+/// from the front, so positions can point into the original.
+fn trim_counted(input: &str) -> (usize, &str) {
+    let body = input.trim_start();
+    let skipped = input[..input.len() - body.len()].chars().count();
+    (skipped, body.trim_end())
+}
+
+pub const ASCII85_VARIANT: OptionSpec = OptionSpec {
+    key: "variant",
+    kind: OptionKind::Choice(&["ascii85", "z85"]),
+    default: "ascii85",
+};
+
+pub struct Ascii85Tool;
+
+impl Tool for Ascii85Tool {
+    fn id(&self) -> &'static str {
+        "ascii85"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE, ASCII85_VARIANT]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let variant = Base85::from_options(options)?;
+        match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => base85_decode(text(input)?, variant).map(Output::Bytes),
+            _ => Ok(Output::Text(base85_encode(input, variant))),
+        }
+    }
+}
+
+// ==================== Base45 ====================
+const BASE45_SYMBOLS: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// RFC 9285.
+pub fn base45_encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity(input.len().div_ceil(2) * 3);
+    for chunk in input.chunks(2) {
+        let (mut value, width) = match *chunk {
+            [a, b] => (u32::from(a) << 8 | u32::from(b), 3),
+            [a] => (u32::from(a), 2),
+            _ => unreachable!(),
+        };
+        for _ in 0..width {
+            out.push(char::from(BASE45_SYMBOLS[(value % 45) as usize]));
+            value /= 45;
+        }
+    }
+    out
+}
+
+/// Space is a Base45 symbol, so only line breaks around the text are
+/// dropped.
+pub fn base45_decode(input: &str) -> Result<Vec<u8>, ToolError> {
+    let body = input.trim_start_matches(['\r', '\n']);
+    let start = input.len() - body.len();
+    let body = body.trim_end_matches(['\r', '\n']);
+    let mut digits = Vec::with_capacity(body.len());
+    for (i, c) in body.chars().enumerate() {
+        let digit = BASE45_SYMBOLS
+            .iter()
+            .position(|&s| char::from(s) == c)
+            .ok_or(ToolError::InvalidCharacter {
+                ch: c,
+                position: start + i + 1,
+            })?;
+        digits.push(digit as u32);
+    }
+    let mut out = Vec::with_capacity(digits.len() / 3 * 2);
+    for (i, chunk) in digits.chunks(3).enumerate() {
+        let value = chunk.iter().rev().fold(0, |value, &d| value * 45 + d);
+        let overflow = ToolError::GroupOverflow {
+            position: start + i * 3 + 1,
+        };
+        match chunk.len() {
+            3 => out.extend_from_slice(&u16::try_from(value).map_err(|_| overflow)?.to_be_bytes()),
+            2 => out.push(u8::try_from(value).map_err(|_| overflow)?),
+            _ => return Err(ToolError::InvalidLength),
+        }
+    }
+    Ok(out)
+}
+
+pub struct Base45Tool;
+
+impl Tool for Base45Tool {
+    fn id(&self) -> &'static str {
+        "base45"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => base45_decode(text(input)?).map(Output::Bytes),
+            _ => Ok(Output::Text(base45_encode(input))),
+        }
+    }
+}
+
+// ==================== Base36 / Base62 ====================
+const BASE36_SYMBOLS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE62_SYMBOLS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The bytes as one big-endian number in base `symbols.len()`, with a
+/// leading zero symbol for each leading zero byte, as Base58 does.
+fn radix_encode(input: &[u8], symbols: &[u8]) -> String {
+    let base = symbols.len() as u32;
+    let zeros = input.iter().take_while(|&&b| b == 0).count();
+    // Least significant first.
+    let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 3 / 2);
+    for &byte in &input[zeros..] {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % base) as u8;
+            carry /= base;
+        }
+        while carry > 0 {
+            digits.push((carry % base) as u8);
+            carry /= base;
+        }
+    }
+    std::iter::repeat_n(symbols[0], zeros)
+        .chain(digits.iter().rev().map(|&d| symbols[usize::from(d)]))
+        .map(char::from)
+        .collect()
+}
+
+/// Ignores whitespace. `digit` maps a character to its value.
+fn radix_decode(
+    input: &str,
+    base: u32,
+    digit: impl Fn(char) -> Option<u32>,
+) -> Result<Vec<u8>, ToolError> {
+    let mut zeros = 0;
+    // Least significant first.
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    for (i, c) in input.chars().enumerate() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let mut carry = digit(c).ok_or(ToolError::InvalidCharacter {
+            ch: c,
+            position: i + 1,
+        })?;
+        if carry == 0 && bytes.is_empty() {
+            zeros += 1;
+            continue;
+        }
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * base;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.extend(std::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Lowercase `0-9a-z`.
+pub fn base36_encode(input: &[u8]) -> String {
+    radix_encode(input, BASE36_SYMBOLS)
+}
+
+/// Case-insensitive.
+pub fn base36_decode(input: &str) -> Result<Vec<u8>, ToolError> {
+    radix_decode(input, 36, |c| c.to_digit(36))
+}
+
+/// `0-9A-Za-z`.
+pub fn base62_encode(input: &[u8]) -> String {
+    radix_encode(input, BASE62_SYMBOLS)
+}
+
+pub fn base62_decode(input: &str) -> Result<Vec<u8>, ToolError> {
+    radix_decode(input, 62, |c| {
+        BASE62_SYMBOLS
+            .iter()
+            .position(|&s| char::from(s) == c)
+            .map(|d| d as u32)
+    })
+}
+
+pub struct Base36Tool;
+
+impl Tool for Base36Tool {
+    fn id(&self) -> &'static str {
+        "base36"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => base36_decode(text(input)?).map(Output::Bytes),
+            _ => Ok(Output::Text(base36_encode(input))),
+        }
+    }
+}
+
+pub struct Base62Tool;
+
+impl Tool for Base62Tool {
+    fn id(&self) -> &'static str {
+        "base62"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => base62_decode(text(input)?).map(Output::Bytes),
+            _ => Ok(Output::Text(base62_encode(input))),
+        }
+    }
+}
+
+// ==================== Base91 ====================
+const BASE91_SYMBOLS: &[u8; 91] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$%&()*+,./:;<=>?@[]^_`{|}~\"";
+
+/// Joachim Henke's basE91.
+pub fn base91_encode(input: &[u8]) -> String {
+    let symbol = |v: u32| char::from(BASE91_SYMBOLS[v as usize]);
+    let mut out = String::with_capacity(input.len() * 5 / 4 + 2);
+    let (mut queue, mut bits) = (0u32, 0u32);
+    for &byte in input {
+        queue |= u32::from(byte) << bits;
+        bits += 8;
+        if bits > 13 {
+            let mut value = queue & 8191;
+            if value > 88 {
+                queue >>= 13;
+                bits -= 13;
+            } else {
+                value = queue & 16383;
+                queue >>= 14;
+                bits -= 14;
+            }
+            out.push(symbol(value % 91));
+            out.push(symbol(value / 91));
+        }
+    }
+    if bits > 0 {
+        out.push(symbol(queue % 91));
+        if bits > 7 || queue > 90 {
+            out.push(symbol(queue / 91));
+        }
+    }
+    out
+}
+
+/// Ignores whitespace.
+pub fn base91_decode(input: &str) -> Result<Vec<u8>, ToolError> {
+    let mut out = Vec::with_capacity(input.len() * 4 / 5);
+    let (mut queue, mut bits) = (0u32, 0u32);
+    let mut pending: Option<u32> = None;
+    for (i, c) in input.chars().enumerate() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let digit = BASE91_SYMBOLS
+            .iter()
+            .position(|&s| char::from(s) == c)
+            .ok_or(ToolError::InvalidCharacter {
+                ch: c,
+                position: i + 1,
+            })? as u32;
+        let Some(low) = pending.take() else {
+            pending = Some(digit);
+            continue;
+        };
+        let value = low + digit * 91;
+        queue |= value << bits;
+        bits += if value & 8191 > 88 { 13 } else { 14 };
+        while bits > 7 {
+            out.push(queue as u8);
+            queue >>= 8;
+            bits -= 8;
+        }
+    }
+    if let Some(low) = pending {
+        out.push((queue | low << bits) as u8);
+    }
+    Ok(out)
+}
+
+pub struct Base91Tool;
+
+impl Tool for Base91Tool {
+    fn id(&self) -> &'static str {
+        "base91"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => base91_decode(text(input)?).map(Output::Bytes),
+            _ => Ok(Output::Text(base91_encode(input))),
+        }
+    }
+}
+
+// ==================== HTML Escape ====================
+pub fn html_escape(input: &str) -> String {
+    html_escape::encode_safe(input).to_string()
+}
+
+pub fn html_unescape(input: &str) -> String {
+    html_escape::decode_html_entities(input).to_string()
+}
+
+pub struct HtmlEscapeTool;
+
+impl Tool for HtmlEscapeTool {
+    fn id(&self) -> &'static str {
+        "html-escape"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let input = text(input)?;
+        Ok(Output::Text(match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => html_unescape(input),
+            _ => html_escape(input),
+        }))
+    }
+}
+
+// ==================== URL Escape ====================
+pub fn url_encode(input: &str) -> String {
+    urlencoding::encode(input).into_owned()
+}
+
+pub fn url_decode(input: &str) -> Result<String, ToolError> {
+    urlencoding::decode(input)
+        .map(|s| s.into_owned())
+        .map_err(|e| ToolError::Utf8(e.to_string()))
+}
+
+pub struct UrlEscapeTool;
+
+impl Tool for UrlEscapeTool {
+    fn id(&self) -> &'static str {
+        "url-escape"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[MODE_ENCODE_DECODE]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let input = text(input)?;
+        match options.value(&MODE_ENCODE_DECODE)? {
+            "decode" => url_decode(input).map(Output::Text),
+            _ => Ok(Output::Text(url_encode(input))),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Per-tool input history. Entries hold the same page state as permalinks,
+//! plus the output the page showed for it.
+
+use super::permalink::State;
+use super::Tool;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Unpinned entries kept per tool; pinned entries do not count.
+pub const HISTORY_LIMIT: usize = 20;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub timestamp: i64,
+    pub state: State,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub output: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+/// Entries of one tool, newest first.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History(Vec<Entry>);
+
+impl History {
+    pub fn entries(&self) -> &[Entry] {
+        &self.0
+    }
+
+    /// Records `state` and its `output` as the newest entry. An entry with the
+    /// same state is moved to the top instead and takes the new output, and
+    /// the oldest unpinned entries beyond `HISTORY_LIMIT` are dropped.
+    pub fn record(&mut self, state: State, output: String, timestamp: i64) {
+        let pinned = match self.0.iter().position(|e| e.state == state) {
+            Some(i) => self.0.remove(i).pinned,
+            None => false,
+        };
+        self.0.insert(
+            0,
+            Entry {
+                timestamp,
+                state,
+                output,
+                pinned,
+            },
+        );
+
+        self.trim();
+    }
+
+    /// Adds the entries of `other`. An entry already present keeps the newer
+    /// timestamp and output and stays pinned if either copy is; the result is
+    /// ordered newest first and trimmed like `record`.
+    pub fn merge(&mut self, other: History) {
+        for entry in other.0 {
+            match self.0.iter_mut().find(|e| e.state == entry.state) {
+                Some(existing) => {
+                    if entry.timestamp > existing.timestamp {
+                        existing.timestamp = entry.timestamp;
+                        existing.output = entry.output;
+                    }
+                    existing.pinned |= entry.pinned;
+                }
+                None => self.0.push(entry),
+            }
+        }
+        self.0.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
+        self.trim();
+    }
+
+    fn trim(&mut self) {
+        let mut unpinned = self.0.iter().filter(|e| !e.pinned).count();
+        while unpinned > HISTORY_LIMIT {
+            if let Some(i) = self.0.iter().rposition(|e| !e.pinned) {
+                self.0.remove(i);
+            }
+            unpinned -= 1;
+        }
+    }
+
+    pub fn toggle_pin(&mut self, index: usize) {
+        if let Some(entry) = self.0.get_mut(index) {
+            entry.pinned = !entry.pinned;
+        }
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        if index < self.0.len() {
+            self.0.remove(index);
+        }
+    }
+
+    /// Drops every entry that is not pinned.
+    pub fn clear(&mut self) {
+        self.0.retain(|e| e.pinned);
+    }
+}
+
+/// History is opt-in: `enabled` turns it on for all tools except sensitive
+/// ones, and `tools` holds per-tool overrides.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HistorySettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub tools: BTreeMap<String, bool>,
+}
+
+impl HistorySettings {
+    pub fn is_enabled(&self, tool: &dyn Tool) -> bool {
+        self.tools
+            .get(tool.id())
+            .copied()
+            .unwrap_or(self.enabled && !tool.sensitive())
+    }
+
+    pub fn set_enabled(&mut self, tool: &dyn Tool, enabled: bool) {
+        self.tools.insert(tool.id().to_string(), enabled);
+    }
+}
@@ -0,0 +1,134 @@
+//! Jobs that may be too slow for the UI thread. The `worker` binary runs them
+//! inside a Web Worker; `Request` and `Reply` are the JSON messages exchanged
+//! with it. Binary input travels next to the JSON rather than inside it.
+
+use super::crypto::{HashAlgorithm, MultiHasher};
+use super::development::{self, DiffLine};
+use super::ToolError;
+use serde::{Deserialize, Serialize};
+
+/// Input is hashed in pieces of this size, with progress after each one.
+pub const CHUNK_SIZE: usize = 1 << 20;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Job {
+    /// Hashes the binary input with every algorithm.
+    Hash,
+    Regex {
+        pattern: String,
+        text: String,
+    },
+    Diff {
+        old: String,
+        new: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum JobOutput {
+    Hashes(Vec<(HashAlgorithm, String)>),
+    Matches(Vec<String>),
+    Diff(Vec<DiffLine>),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Request {
+    pub id: u32,
+    pub job: Job,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Reply {
+    Progress {
+        id: u32,
+        done: u64,
+        total: u64,
+    },
+    Done {
+        id: u32,
+        result: Result<JobOutput, ToolError>,
+    },
+}
+
+impl Reply {
+    pub fn id(&self) -> u32 {
+        match self {
+            Reply::Progress { id, .. } | Reply::Done { id, .. } => *id,
+        }
+    }
+}
+
+/// Input that a job reads piece by piece, so it never has to hold all of it.
+pub trait Source {
+    fn size(&self) -> u64;
+    /// Reads `len` bytes at `offset`; fewer only at the end of the input.
+    fn read(&mut self, offset: u64, len: usize) -> Result<Vec<u8>, ToolError>;
+}
+
+impl Source for &[u8] {
+    fn size(&self) -> u64 {
+        self.len() as u64
+    }
+
+    fn read(&mut self, offset: u64, len: usize) -> Result<Vec<u8>, ToolError> {
+        let start = (offset as usize).min(self.len());
+        let end = start.saturating_add(len).min(self.len());
+        Ok(self[start..end].to_vec())
+    }
+}
+
+/// Runs `job` over `data`, calling `progress(done, total)` as it goes.
+pub fn run(
+    job: &Job,
+    mut data: &[u8],
+    progress: &mut dyn FnMut(u64, u64),
+) -> Result<JobOutput, ToolError> {
+    run_from(job, &mut data, progress)
+}
+
+/// Like `run`, reading the input from `source` in `CHUNK_SIZE` pieces.
+pub fn run_from(
+    job: &Job,
+    source: &mut dyn Source,
+    progress: &mut dyn FnMut(u64, u64),
+) -> Result<JobOutput, ToolError> {
+    match job {
+        Job::Hash => {
+            let total = source.size();
+            let mut hasher = MultiHasher::default();
+            let mut done = 0;
+            while done < total {
+                let len = (total - done).min(CHUNK_SIZE as u64) as usize;
+                let chunk = source.read(done, len)?;
+                if chunk.is_empty() {
+                    return Err(ToolError::Read(format!("unexpected end at byte {done}")));
+                }
+                hasher.update(&chunk);
+                done += chunk.len() as u64;
+                progress(done, total);
+            }
+            Ok(JobOutput::Hashes(hasher.finalize()))
+        }
+        Job::Regex { pattern, text } => {
+            development::regex_find(pattern, text).map(JobOutput::Matches)
+        }
+        Job::Diff { old, new } => Ok(JobOutput::Diff(development::diff_lines(old, new))),
+    }
+}
+
+/// Human-readable byte count using binary units, e.g. `1.5 MiB`.
+pub fn format_size(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes.max(0.0);
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", value as u64)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
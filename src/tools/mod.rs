@@ -0,0 +1,295 @@
+//! UI-independent tool logic shared by the pages in `app`, the tests and any
+//! other frontend.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+pub mod bytes;
+pub mod crypto;
+pub mod detect;
+pub mod development;
+pub mod encoding;
+pub mod history;
+pub mod job;
+pub mod permalink;
+pub mod pipeline;
+pub mod search;
+pub mod settings;
+pub mod utilities;
+
+pub use crate::i18n::Lang;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ToolError {
+    Decode(String),
+    Utf8(String),
+    InvalidBase32,
+    /// `position` counts characters from 1.
+    InvalidCharacter {
+        ch: char,
+        position: usize,
+    },
+    /// Ends partway through a group.
+    InvalidLength,
+    /// A group decodes to more than its bytes can hold; `position` is that
+    /// of its first character.
+    GroupOverflow {
+        position: usize,
+    },
+    InvalidHex(String),
+    MissingChecksum,
+    /// Hex checksums.
+    ChecksumMismatch {
+        expected: String,
+        found: String,
+    },
+    InvalidKey,
+    InvalidJwt,
+    JwtHeader(String),
+    Json(String),
+    Regex(String),
+    Crypto(String),
+    InvalidCron,
+    InvalidNumber,
+    InvalidTimestamp,
+    InvalidOption {
+        key: String,
+        value: String,
+    },
+    UnknownTool(String),
+    UnsupportedVersion(u32),
+    Read(String),
+    /// A file given where text is expected is not UTF-8; holds its name.
+    NotText(String),
+}
+
+impl ToolError {
+    pub fn localized(&self, lang: Lang) -> String {
+        match self {
+            ToolError::Decode(e) => lang.format("error.decode", &[("message", e)]),
+            ToolError::Utf8(e) => lang.format("error.utf8", &[("message", e)]),
+            ToolError::InvalidBase32 => lang.t("error.invalid-base32").to_string(),
+            ToolError::InvalidCharacter { ch, position } => lang.format(
+                "error.invalid-character",
+                &[
+                    ("char", &ch.to_string()),
+                    ("position", &position.to_string()),
+                ],
+            ),
+            ToolError::InvalidHex(e) => lang.format("error.invalid-hex", &[("message", e)]),
+            ToolError::InvalidLength => lang.t("error.invalid-length").to_string(),
+            ToolError::GroupOverflow { position } => lang.format(
+                "error.group-overflow",
+                &[("position", &position.to_string())],
+            ),
+            ToolError::MissingChecksum => lang.t("error.missing-checksum").to_string(),
+            ToolError::ChecksumMismatch { expected, found } => lang.format(
+                "error.checksum-mismatch",
+                &[("expected", expected), ("found", found)],
+            ),
+            ToolError::InvalidKey => lang.t("error.invalid-key").to_string(),
+            ToolError::InvalidJwt => lang.t("error.invalid-jwt").to_string(),
+            ToolError::JwtHeader(e) => lang.format("error.jwt-header", &[("message", e)]),
+            ToolError::Json(e) | ToolError::Regex(e) | ToolError::Crypto(e) => e.clone(),
+            ToolError::InvalidCron => lang.t("error.invalid-cron").to_string(),
+            ToolError::InvalidNumber => lang.t("error.invalid-number").to_string(),
+            ToolError::InvalidTimestamp => lang.t("error.invalid-timestamp").to_string(),
+            ToolError::InvalidOption { key, value } => {
+                lang.format("error.invalid-option", &[("key", key), ("value", value)])
+            }
+            ToolError::UnknownTool(id) => lang.format("error.unknown-tool", &[("id", id)]),
+            ToolError::UnsupportedVersion(v) => {
+                lang.format("error.unsupported-version", &[("version", &v.to_string())])
+            }
+            ToolError::Read(e) => lang.format("error.read", &[("message", e)]),
+            ToolError::NotText(name) => lang.format("error.not-text", &[("name", name)]),
+        }
+    }
+}
+
+impl fmt::Display for ToolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.localized(Lang::En))
+    }
+}
+
+impl std::error::Error for ToolError {}
+
+impl From<std::string::FromUtf8Error> for ToolError {
+    fn from(e: std::string::FromUtf8Error) -> Self {
+        ToolError::Utf8(e.to_string())
+    }
+}
+
+impl From<std::str::Utf8Error> for ToolError {
+    fn from(e: std::str::Utf8Error) -> Self {
+        ToolError::Utf8(e.to_string())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OptionKind {
+    Choice(&'static [&'static str]),
+    Text,
+    Secret,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OptionSpec {
+    pub key: &'static str,
+    pub kind: OptionKind,
+    pub default: &'static str,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Options(BTreeMap<String, String>);
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    pub fn set(&mut self, key: &str, value: impl Into<String>) {
+        self.0.insert(key.to_string(), value.into());
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    pub fn remove(&mut self, key: &str) {
+        self.0.remove(key);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Looks up `spec.key`, falling back to the spec default, and rejects
+    /// values outside of a `Choice` list.
+    pub fn value<'a>(&'a self, spec: &'a OptionSpec) -> Result<&'a str, ToolError> {
+        let value = self.get(spec.key).unwrap_or(spec.default);
+        match spec.kind {
+            OptionKind::Choice(choices) if !choices.contains(&value) => {
+                Err(ToolError::InvalidOption {
+                    key: spec.key.to_string(),
+                    value: value.to_string(),
+                })
+            }
+            _ => Ok(value),
+        }
+    }
+
+    /// Checks that every option exists for `tool` and holds an allowed value.
+    pub fn validate(&self, tool: &dyn Tool) -> Result<(), ToolError> {
+        for (key, value) in self.iter() {
+            let spec = tool
+                .options()
+                .iter()
+                .find(|s| s.key == key)
+                .ok_or_else(|| ToolError::InvalidOption {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })?;
+            self.value(spec)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Output {
+    Text(String),
+    Bytes(Vec<u8>),
+    Fields(Vec<(String, String)>),
+}
+
+impl Output {
+    /// Renders the output as text; bytes are decoded lossily.
+    pub fn to_text(&self) -> String {
+        match self {
+            Output::Text(s) => s.clone(),
+            Output::Bytes(b) => String::from_utf8_lossy(b).into_owned(),
+            Output::Fields(fields) => fields
+                .iter()
+                .map(|(label, value)| format!("{}: {}", label, value))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Output::Bytes(b) => b,
+            other => other.to_text().into_bytes(),
+        }
+    }
+}
+
+pub trait Tool: Sync {
+    fn id(&self) -> &'static str;
+    /// Display name from the `tool.<id>` catalog message.
+    fn name(&self, lang: Lang) -> &'static str {
+        lang.lookup(&format!("tool.{}", self.id()))
+            .unwrap_or(self.id())
+    }
+    fn options(&self) -> &'static [OptionSpec] {
+        &[]
+    }
+    /// Whether inputs typically hold keys or tokens, which keeps the tool out
+    /// of history unless the user opts in for it explicitly.
+    fn sensitive(&self) -> bool {
+        false
+    }
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError>;
+}
+
+pub(crate) fn text(input: &[u8]) -> Result<&str, ToolError> {
+    Ok(std::str::from_utf8(input)?)
+}
+
+pub(crate) const MODE_ENCODE_DECODE: OptionSpec = OptionSpec {
+    key: "mode",
+    kind: OptionKind::Choice(&["encode", "decode"]),
+    default: "encode",
+};
+
+static TOOLS: &[&dyn Tool] = &[
+    &encoding::Base64Tool,
+    &encoding::Base32Tool,
+    &encoding::Base58Tool,
+    &encoding::Ascii85Tool,
+    &encoding::Base45Tool,
+    &encoding::Base36Tool,
+    &encoding::Base62Tool,
+    &encoding::Base91Tool,
+    &encoding::HtmlEscapeTool,
+    &encoding::UrlEscapeTool,
+    &crypto::HashTool,
+    &crypto::AesTool,
+    &crypto::JwtTool,
+    &development::JsonTool,
+    &development::RegexTool,
+    &development::DiffTool,
+    &development::UuidTool,
+    &development::TimestampTool,
+    &development::BaseConvTool,
+    &development::HexDumpTool,
+    &utilities::CronTool,
+    &utilities::ImageBase64Tool,
+];
+
+pub fn all() -> &'static [&'static dyn Tool] {
+    TOOLS
+}
+
+pub fn find(id: &str) -> Option<&'static dyn Tool> {
+    TOOLS.iter().copied().find(|t| t.id() == id)
+}
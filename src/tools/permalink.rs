@@ -0,0 +1,47 @@
+//! Share links: page state serialized into the URL fragment, which browsers
+//! never send to the server.
+
+use super::ToolError;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use std::collections::BTreeMap;
+
+pub type State = BTreeMap<String, String>;
+
+const RAW: &str = "j=";
+const DEFLATE: &str = "z=";
+/// Upper bound for inflated state so a crafted link cannot exhaust memory.
+const MAX_STATE_LEN: usize = 4 << 20;
+
+/// Encodes `state` as a fragment (without the leading `#`), deflating the
+/// JSON when that makes the link shorter.
+pub fn encode(state: &State) -> String {
+    let json = serde_json::to_vec(state).unwrap();
+    let deflated = miniz_oxide::deflate::compress_to_vec(&json, 9);
+    if deflated.len() < json.len() {
+        format!("{}{}", DEFLATE, URL_SAFE_NO_PAD.encode(deflated))
+    } else {
+        format!("{}{}", RAW, URL_SAFE_NO_PAD.encode(json))
+    }
+}
+
+pub fn decode(fragment: &str) -> Result<State, ToolError> {
+    let fragment = fragment.strip_prefix('#').unwrap_or(fragment);
+    let (compressed, payload) = if let Some(payload) = fragment.strip_prefix(DEFLATE) {
+        (true, payload)
+    } else if let Some(payload) = fragment.strip_prefix(RAW) {
+        (false, payload)
+    } else {
+        return Err(ToolError::Decode("not a permalink".into()));
+    };
+
+    let bytes = URL_SAFE_NO_PAD
+        .decode(payload)
+        .map_err(|e| ToolError::Decode(e.to_string()))?;
+    let json = if compressed {
+        miniz_oxide::inflate::decompress_to_vec_with_limit(&bytes, MAX_STATE_LEN)
+            .map_err(|e| ToolError::Decode(e.to_string()))?
+    } else {
+        bytes
+    };
+    serde_json::from_slice(&json).map_err(|e| ToolError::Json(e.to_string()))
+}
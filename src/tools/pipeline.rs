@@ -0,0 +1,112 @@
+use super::{find, OptionKind, Options, Output, ToolError};
+use serde::{Deserialize, Serialize};
+
+pub const RECIPE_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Step {
+    pub tool: String,
+    #[serde(default)]
+    pub options: Options,
+}
+
+impl Step {
+    pub fn new(tool: &str, options: Options) -> Self {
+        Self {
+            tool: tool.to_string(),
+            options,
+        }
+    }
+}
+
+/// A named chain of tool steps; each step receives the previous step's
+/// output bytes as its input.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Recipe {
+    pub version: u32,
+    #[serde(default)]
+    pub name: String,
+    pub steps: Vec<Step>,
+}
+
+impl Default for Recipe {
+    fn default() -> Self {
+        Self {
+            version: RECIPE_VERSION,
+            name: String::new(),
+            steps: Vec::new(),
+        }
+    }
+}
+
+impl Recipe {
+    pub fn new(name: &str, steps: Vec<Step>) -> Self {
+        Self {
+            name: name.to_string(),
+            steps,
+            ..Self::default()
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ToolError> {
+        let recipe: Recipe =
+            serde_json::from_str(json).map_err(|e| ToolError::Json(e.to_string()))?;
+        recipe.validate()?;
+        Ok(recipe)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// The recipe without `Secret` options such as keys, for sharing and
+    /// storing.
+    pub fn without_secrets(&self) -> Recipe {
+        let mut recipe = self.clone();
+        for step in &mut recipe.steps {
+            let Some(tool) = find(&step.tool) else {
+                continue;
+            };
+            for spec in tool.options() {
+                if spec.kind == OptionKind::Secret {
+                    step.options.remove(spec.key);
+                }
+            }
+        }
+        recipe
+    }
+
+    /// Checks the version, that every step names a known tool and that its
+    /// options exist and hold allowed values.
+    pub fn validate(&self) -> Result<(), ToolError> {
+        if self.version > RECIPE_VERSION {
+            return Err(ToolError::UnsupportedVersion(self.version));
+        }
+        for step in &self.steps {
+            let tool = find(&step.tool).ok_or_else(|| ToolError::UnknownTool(step.tool.clone()))?;
+            step.options.validate(tool)?;
+        }
+        Ok(())
+    }
+
+    /// Runs every step and returns each intermediate result, stopping after
+    /// the first failing step.
+    pub fn run(&self, input: &[u8]) -> Vec<Result<Output, ToolError>> {
+        let mut results = Vec::with_capacity(self.steps.len());
+        let mut current = input.to_vec();
+        for step in &self.steps {
+            let result = find(&step.tool)
+                .ok_or_else(|| ToolError::UnknownTool(step.tool.clone()))
+                .and_then(|tool| tool.run(&current, &step.options));
+            match &result {
+                Ok(output) => current = output.clone().into_bytes(),
+                Err(_) => {
+                    results.push(result);
+                    break;
+                }
+            }
+            results.push(result);
+        }
+        results
+    }
+}
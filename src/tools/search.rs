@@ -0,0 +1,43 @@
+//! Fuzzy matching for the command palette.
+
+/// Scores `candidate` against `query` when every query character appears in
+/// order (ignoring case and whitespace in the query). Consecutive matches and
+/// matches at word starts score higher; `None` means no match.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let candidate: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+
+    for q in query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+    {
+        let offset = candidate[next..].iter().position(|&c| c == q)?;
+        let index = next + offset;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !candidate[index - 1].is_alphanumeric() {
+            score += 8;
+        }
+        score -= offset.min(10) as i32;
+        previous = Some(index);
+        next = index + 1;
+    }
+    Some(score)
+}
+
+/// Returns the indices of `candidates` matching `query`, best first; ties keep
+/// their original order.
+pub fn fuzzy_filter<S: AsRef<str>>(query: &str, candidates: &[S]) -> Vec<usize> {
+    let mut scored: Vec<(usize, i32)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| fuzzy_score(query, c.as_ref()).map(|s| (i, s)))
+        .collect();
+    scored.sort_by_key(|&(i, s)| (-s, i));
+    scored.into_iter().map(|(i, _)| i).collect()
+}
@@ -0,0 +1,236 @@
+//! Everything the site keeps in localStorage, and the versioned JSON bundle
+//! that moves it between browsers. Other sites on the same origin share
+//! localStorage, so only the keys listed here are ever read or cleared.
+
+use super::history::{History, HistorySettings};
+use super::pipeline::Recipe;
+use super::{find, Lang, Options, ToolError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const BUNDLE_VERSION: u32 = 1;
+
+pub const THEME_KEY: &str = "theme";
+pub const LANG_KEY: &str = "lang";
+pub const HISTORY_SETTINGS_KEY: &str = "history-settings";
+pub const RECIPES_KEY: &str = "recipes";
+const HISTORY_PREFIX: &str = "history:";
+const PREFS_PREFIX: &str = "prefs:";
+
+/// Storage key of a tool's history.
+pub fn history_key(tool_id: &str) -> String {
+    format!("{HISTORY_PREFIX}{tool_id}")
+}
+
+/// Storage key of a tool's remembered options.
+pub fn prefs_key(tool_id: &str) -> String {
+    format!("{PREFS_PREFIX}{tool_id}")
+}
+
+/// Whether `key` is one of the site's storage keys.
+pub fn is_site_key(key: &str) -> bool {
+    matches!(
+        key,
+        THEME_KEY | LANG_KEY | HISTORY_SETTINGS_KEY | RECIPES_KEY
+    ) || key.starts_with(HISTORY_PREFIX)
+        || key.starts_with(PREFS_PREFIX)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Imported values win; everything else is kept.
+    Merge,
+    /// Site data not in the bundle is dropped.
+    Replace,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Bundle {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+    #[serde(default)]
+    pub history_settings: HistorySettings,
+    /// Remembered options by tool id.
+    #[serde(default)]
+    pub prefs: BTreeMap<String, Options>,
+    #[serde(default)]
+    pub recipes: Vec<Recipe>,
+    /// History by tool id.
+    #[serde(default)]
+    pub history: BTreeMap<String, History>,
+}
+
+impl Default for Bundle {
+    fn default() -> Self {
+        Self {
+            version: BUNDLE_VERSION,
+            theme: None,
+            lang: None,
+            history_settings: HistorySettings::default(),
+            prefs: BTreeMap::new(),
+            recipes: Vec::new(),
+            history: BTreeMap::new(),
+        }
+    }
+}
+
+impl Bundle {
+    /// Collects the site's entries from localStorage `(key, value)` pairs.
+    /// Values that no longer parse are skipped.
+    pub fn from_storage(entries: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut bundle = Bundle::default();
+        for (key, value) in entries {
+            match key.as_str() {
+                THEME_KEY => bundle.theme = Some(value),
+                LANG_KEY => bundle.lang = Some(value),
+                HISTORY_SETTINGS_KEY => {
+                    if let Ok(settings) = serde_json::from_str(&value) {
+                        bundle.history_settings = settings;
+                    }
+                }
+                RECIPES_KEY => {
+                    if let Ok(recipes) = serde_json::from_str(&value) {
+                        bundle.recipes = recipes;
+                    }
+                }
+                _ => {
+                    if let Some(tool) = key.strip_prefix(HISTORY_PREFIX) {
+                        if let Ok(history) = serde_json::from_str(&value) {
+                            bundle.history.insert(tool.to_string(), history);
+                        }
+                    } else if let Some(tool) = key.strip_prefix(PREFS_PREFIX) {
+                        if let Ok(options) = serde_json::from_str(&value) {
+                            bundle.prefs.insert(tool.to_string(), options);
+                        }
+                    }
+                }
+            }
+        }
+        bundle
+    }
+
+    /// The `(key, value)` pairs to write to localStorage.
+    pub fn to_storage(&self) -> Vec<(String, String)> {
+        let mut entries = Vec::new();
+        if let Some(theme) = &self.theme {
+            entries.push((THEME_KEY.to_string(), theme.clone()));
+        }
+        if let Some(lang) = &self.lang {
+            entries.push((LANG_KEY.to_string(), lang.clone()));
+        }
+        if self.history_settings != HistorySettings::default() {
+            entries.push((
+                HISTORY_SETTINGS_KEY.to_string(),
+                json(&self.history_settings),
+            ));
+        }
+        if !self.recipes.is_empty() {
+            entries.push((RECIPES_KEY.to_string(), json(&self.recipes)));
+        }
+        for (tool, options) in &self.prefs {
+            entries.push((prefs_key(tool), json(options)));
+        }
+        for (tool, history) in &self.history {
+            entries.push((history_key(tool), json(history)));
+        }
+        entries
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ToolError> {
+        // The version first, so newer bundles are reported as such rather
+        // than as unknown fields.
+        #[derive(Deserialize)]
+        struct Versioned {
+            version: u32,
+        }
+        let Versioned { version } =
+            serde_json::from_str(json).map_err(|e| ToolError::Json(e.to_string()))?;
+        if version > BUNDLE_VERSION {
+            return Err(ToolError::UnsupportedVersion(version));
+        }
+        let bundle: Bundle =
+            serde_json::from_str(json).map_err(|e| ToolError::Json(e.to_string()))?;
+        bundle.validate()?;
+        Ok(bundle)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Checks the version, the language, every recipe, and that prefs and
+    /// history belong to known tools. The theme is left to the app.
+    pub fn validate(&self) -> Result<(), ToolError> {
+        if self.version > BUNDLE_VERSION {
+            return Err(ToolError::UnsupportedVersion(self.version));
+        }
+        if let Some(lang) = &self.lang {
+            Lang::from_code(lang).ok_or_else(|| ToolError::InvalidOption {
+                key: LANG_KEY.to_string(),
+                value: lang.clone(),
+            })?;
+        }
+        for recipe in &self.recipes {
+            recipe.validate()?;
+        }
+        for (tool, options) in &self.prefs {
+            let tool = find(tool).ok_or_else(|| ToolError::UnknownTool(tool.clone()))?;
+            options.validate(tool)?;
+        }
+        for tool in self
+            .history
+            .keys()
+            .chain(self.history_settings.tools.keys())
+        {
+            find(tool).ok_or_else(|| ToolError::UnknownTool(tool.clone()))?;
+        }
+        Ok(())
+    }
+
+    /// Merges `imported` into `self`: its theme, language and settings win,
+    /// recipes with the same name are replaced and history is combined.
+    pub fn merge(&mut self, imported: Bundle) {
+        self.theme = imported.theme.or(self.theme.take());
+        self.lang = imported.lang.or(self.lang.take());
+        self.history_settings.enabled = imported.history_settings.enabled;
+        self.history_settings
+            .tools
+            .extend(imported.history_settings.tools);
+        self.prefs.extend(imported.prefs);
+        for recipe in imported.recipes {
+            match self.recipes.iter_mut().find(|r| r.name == recipe.name) {
+                Some(existing) => *existing = recipe,
+                None => self.recipes.push(recipe),
+            }
+        }
+        for (tool, history) in imported.history {
+            self.history.entry(tool).or_default().merge(history);
+        }
+    }
+
+    /// The site data after importing `imported` into `self`.
+    pub fn import(mut self, imported: Bundle, mode: ImportMode) -> Bundle {
+        match mode {
+            ImportMode::Merge => {
+                self.merge(imported);
+                self
+            }
+            ImportMode::Replace => imported,
+        }
+    }
+
+    /// `(recipes, tools with history, history entries)`, for the import
+    /// summary.
+    pub fn counts(&self) -> (usize, usize, usize) {
+        let entries = self.history.values().map(|h| h.entries().len()).sum();
+        (self.recipes.len(), self.history.len(), entries)
+    }
+}
+
+fn json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap()
+}
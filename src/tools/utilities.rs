@@ -0,0 +1,213 @@
+use super::{text, OptionKind, OptionSpec, Options, Output, Tool, ToolError};
+
+// ==================== Cron ====================
+pub fn cron_describe(expr: &str) -> Result<String, ToolError> {
+    let parts: Vec<&str> = expr.split_whitespace().collect();
+    if parts.len() < 5 {
+        return Err(ToolError::InvalidCron);
+    }
+
+    Ok(format!(
+        "Minute: {}, Hour: {}, Day: {}, Month: {}, Weekday: {}",
+        parts[0], parts[1], parts[2], parts[3], parts[4]
+    ))
+}
+
+/// A five-field cron expression: minute, hour, day of month, month and day
+/// of week. Fields take `*`, numbers, `a-b` ranges, `/step`, comma lists,
+/// and `jan`-`dec` / `sun`-`sat` names; day of week 7 is Sunday.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Whether day of month and day of week are both restricted, in which
+    /// case a day matching either one runs.
+    either_day: bool,
+}
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Parses one field into a bit set of the values in `min..=max`.
+fn cron_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, ToolError> {
+    let value = |s: &str| -> Result<u32, ToolError> {
+        let lower = s.to_ascii_lowercase();
+        let n = match names.iter().position(|name| *name == lower) {
+            Some(i) => i as u32 + min,
+            None => s.parse().map_err(|_| ToolError::InvalidCron)?,
+        };
+        if n < min || n > max {
+            return Err(ToolError::InvalidCron);
+        }
+        Ok(n)
+    };
+
+    let mut set = 0u64;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, step.parse().map_err(|_| ToolError::InvalidCron)?),
+            None => (item, 1),
+        };
+        if step == 0 {
+            return Err(ToolError::InvalidCron);
+        }
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((a, b)) => (value(a)?, value(b)?),
+                None if item.contains('/') => (value(range)?, max),
+                None => (value(range)?, value(range)?),
+            },
+        };
+        if start > end {
+            return Err(ToolError::InvalidCron);
+        }
+        for n in (start..=end).step_by(step as usize) {
+            set |= 1 << n;
+        }
+    }
+    Ok(set)
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<Self, ToolError> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(ToolError::InvalidCron);
+        };
+        let mut weekdays = cron_field(weekday, 0, 7, &WEEKDAY_NAMES)?;
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+        Ok(CronSchedule {
+            minutes: cron_field(minute, 0, 59, &[])?,
+            hours: cron_field(hour, 0, 23, &[])?,
+            days: cron_field(day, 1, 31, &[])?,
+            months: cron_field(month, 1, 12, &MONTH_NAMES)?,
+            weekdays,
+            either_day: !day.starts_with('*') && !weekday.starts_with('*'),
+        })
+    }
+
+    fn day_matches(&self, date: chrono::NaiveDate) -> bool {
+        use chrono::Datelike;
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        if self.either_day {
+            day || weekday
+        } else {
+            day && weekday
+        }
+    }
+
+    /// The next `count` run times strictly after `from`, to the minute.
+    /// Stops early when nothing matches within eight years.
+    pub fn next_after(
+        &self,
+        from: chrono::NaiveDateTime,
+        count: usize,
+    ) -> Vec<chrono::NaiveDateTime> {
+        use chrono::{Datelike, Duration, NaiveDate, Timelike};
+
+        let mut runs = Vec::new();
+        let Some(mut t) = from
+            .with_second(0)
+            .and_then(|t| t.with_nanosecond(0))
+            .map(|t| t + Duration::minutes(1))
+        else {
+            return runs;
+        };
+        let last_year = from.year() + 8;
+        while runs.len() < count && t.year() <= last_year {
+            if self.months & (1 << t.month()) == 0 {
+                let (year, month) = match t.month() {
+                    12 => (t.year() + 1, 1),
+                    m => (t.year(), m + 1),
+                };
+                t = NaiveDate::from_ymd_opt(year, month, 1)
+                    .and_then(|d| d.and_hms_opt(0, 0, 0))
+                    .expect("first of a month is valid");
+            } else if !self.day_matches(t.date()) {
+                t = (t.date() + Duration::days(1))
+                    .and_hms_opt(0, 0, 0)
+                    .expect("midnight is valid");
+            } else if self.hours & (1 << t.hour()) == 0 {
+                t = t.with_minute(0).expect("minute 0 is valid") + Duration::hours(1);
+            } else if self.minutes & (1 << t.minute()) == 0 {
+                t += Duration::minutes(1);
+            } else {
+                runs.push(t);
+                t += Duration::minutes(1);
+            }
+        }
+        runs
+    }
+}
+
+/// The next `count` run times of `expr` after `from`, in UTC.
+pub fn cron_next(
+    expr: &str,
+    from: chrono::DateTime<chrono::Utc>,
+    count: usize,
+) -> Result<Vec<chrono::DateTime<chrono::Utc>>, ToolError> {
+    let schedule = CronSchedule::parse(expr)?;
+    Ok(schedule
+        .next_after(from.naive_utc(), count)
+        .into_iter()
+        .map(|t| t.and_utc())
+        .collect())
+}
+
+pub struct CronTool;
+
+impl Tool for CronTool {
+    fn id(&self) -> &'static str {
+        "cron"
+    }
+
+    fn run(&self, input: &[u8], _options: &Options) -> Result<Output, ToolError> {
+        cron_describe(text(input)?).map(Output::Text)
+    }
+}
+
+// ==================== Image Base64 ====================
+pub fn image_data_uri(mime: &str, encoded: &str) -> String {
+    format!("data:{};base64,{}", mime, encoded)
+}
+
+const IMAGE_MIME: OptionSpec = OptionSpec {
+    key: "mime",
+    kind: OptionKind::Text,
+    default: "application/octet-stream",
+};
+
+const IMAGE_FORMAT: OptionSpec = OptionSpec {
+    key: "format",
+    kind: OptionKind::Choice(&["data-uri", "base64"]),
+    default: "data-uri",
+};
+
+pub struct ImageBase64Tool;
+
+impl Tool for ImageBase64Tool {
+    fn id(&self) -> &'static str {
+        "image-base64"
+    }
+
+    fn options(&self) -> &'static [OptionSpec] {
+        &[IMAGE_MIME, IMAGE_FORMAT]
+    }
+
+    fn run(&self, input: &[u8], options: &Options) -> Result<Output, ToolError> {
+        let encoded = super::encoding::base64_encode(input);
+        Ok(Output::Text(match options.value(&IMAGE_FORMAT)? {
+            "base64" => encoded,
+            _ => image_data_uri(options.value(&IMAGE_MIME)?, &encoded),
+        }))
+    }
+}
@@ -0,0 +1,299 @@
+//! Main-thread side of the job worker. Each page owns a `JobRunner` that runs
+//! one job at a time: submitting a new job supersedes the running one, and
+//! cancelling terminates the worker, which is respawned on the next submit.
+//! Without Worker support jobs run on the main thread instead.
+
+use crate::tools::crypto::MultiHasher;
+use crate::tools::job::{self, Job, JobOutput, Reply, Request, CHUNK_SIZE};
+use crate::tools::ToolError;
+use leptos::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Trunk's loader for the `worker` binary, resolved against `<base href>`.
+const WORKER_URL: &str = "worker_loader.js";
+
+#[derive(Clone)]
+enum Input {
+    Bytes(Vec<u8>),
+    /// Read in slices, so its size does not matter.
+    Blob(web_sys::Blob),
+}
+
+struct Pending {
+    id: u32,
+    /// Kept so the job can still run here if the worker fails to load.
+    job: Job,
+    input: Input,
+    on_done: Box<dyn FnOnce(Result<JobOutput, ToolError>)>,
+}
+
+#[derive(Default)]
+struct State {
+    worker: Option<web_sys::Worker>,
+    onmessage: Option<Closure<dyn FnMut(web_sys::MessageEvent)>>,
+    onerror: Option<Closure<dyn FnMut()>>,
+    /// Set once the worker script could not be started.
+    unavailable: bool,
+    next_id: u32,
+    pending: Option<Pending>,
+}
+
+impl State {
+    fn terminate(&mut self) {
+        if let Some(worker) = self.worker.take() {
+            worker.terminate();
+        }
+        self.onmessage = None;
+        self.onerror = None;
+    }
+
+    fn pending_id(&self) -> Option<u32> {
+        self.pending.as_ref().map(|p| p.id)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct JobRunner {
+    state: StoredValue<Rc<RefCell<State>>>,
+    progress: RwSignal<Option<(u64, u64)>>,
+    busy: RwSignal<bool>,
+    started_at: RwSignal<f64>,
+}
+
+impl JobRunner {
+    pub fn new() -> Self {
+        let runner = JobRunner {
+            state: store_value(Rc::default()),
+            progress: create_rw_signal(None),
+            busy: create_rw_signal(false),
+            started_at: create_rw_signal(0.0),
+        };
+        on_cleanup(move || {
+            runner
+                .state
+                .try_with_value(|state| state.borrow_mut().terminate());
+        });
+        runner
+    }
+
+    pub fn busy(&self) -> bool {
+        self.busy.get()
+    }
+
+    /// `(done, total)` of the running job, once it has reported any.
+    pub fn progress(&self) -> Option<(u64, u64)> {
+        self.progress.get()
+    }
+
+    /// Bytes per second processed so far by the running job.
+    pub fn throughput(&self) -> Option<f64> {
+        let (done, _) = self.progress.get()?;
+        let seconds = (js_sys::Date::now() - self.started_at.get_untracked()) / 1000.0;
+        (seconds > 0.0).then(|| done as f64 / seconds)
+    }
+
+    /// Runs `job` over `data` and hands the result to `on_done`, unless it is
+    /// cancelled or superseded first.
+    pub fn submit(
+        &self,
+        job: Job,
+        data: &[u8],
+        on_done: impl FnOnce(Result<JobOutput, ToolError>) + 'static,
+    ) {
+        self.start(job, Input::Bytes(data.to_vec()), Box::new(on_done));
+    }
+
+    /// Like `submit`, reading the input from `blob` one slice at a time.
+    pub fn submit_blob(
+        &self,
+        job: Job,
+        blob: web_sys::Blob,
+        on_done: impl FnOnce(Result<JobOutput, ToolError>) + 'static,
+    ) {
+        self.start(job, Input::Blob(blob), Box::new(on_done));
+    }
+
+    /// Drops the running job, if any.
+    pub fn cancel(&self) {
+        let state = self.state.get_value();
+        let mut state = state.borrow_mut();
+        if state.pending.take().is_some() {
+            state.terminate();
+        }
+        self.progress.set(None);
+        self.busy.set(false);
+    }
+
+    fn start(
+        &self,
+        job: Job,
+        input: Input,
+        on_done: Box<dyn FnOnce(Result<JobOutput, ToolError>)>,
+    ) {
+        let state = self.state.get_value();
+        let (id, worker) = {
+            let mut state = state.borrow_mut();
+            if state.pending.take().is_some() {
+                // The worker is busy with a job nobody wants any more.
+                state.terminate();
+            }
+            if state.worker.is_none() && !state.unavailable {
+                self.spawn(&mut state);
+            }
+            state.next_id = state.next_id.wrapping_add(1);
+            state.pending = Some(Pending {
+                id: state.next_id,
+                job: job.clone(),
+                input: input.clone(),
+                on_done,
+            });
+            (state.next_id, state.worker.clone())
+        };
+        self.progress.set(None);
+        self.busy.set(true);
+        self.started_at.set(js_sys::Date::now());
+
+        let Some(worker) = worker else {
+            self.run_here();
+            return;
+        };
+        let json = serde_json::to_string(&Request { id, job }).unwrap_or_default();
+        let message = js_sys::Array::of1(&JsValue::from_str(&json));
+        let transfer = js_sys::Array::new();
+        match input {
+            Input::Bytes(data) if data.is_empty() => {}
+            Input::Bytes(data) => {
+                let bytes = js_sys::Uint8Array::from(data.as_slice());
+                transfer.push(&bytes.buffer());
+                message.push(&bytes);
+            }
+            Input::Blob(blob) => {
+                message.push(&blob);
+            }
+        }
+        if worker
+            .post_message_with_transfer(&message, &transfer)
+            .is_err()
+        {
+            self.cancel();
+        }
+    }
+
+    /// Runs the pending job on the main thread. Blobs are still read one
+    /// slice per task, so the page keeps responding and can cancel.
+    fn run_here(&self) {
+        let state = self.state.get_value();
+        let (id, job, input) = match &state.borrow().pending {
+            Some(p) => (p.id, p.job.clone(), p.input.clone()),
+            None => return,
+        };
+        let runner = *self;
+        match input {
+            Input::Bytes(data) => runner.finish(id, job::run(&job, &data, &mut |_, _| {})),
+            Input::Blob(blob) => spawn_local(async move {
+                let total = blob.size() as u64;
+                let mut hasher = MultiHasher::default();
+                let mut done = 0;
+                while done < total {
+                    let end = (done + CHUNK_SIZE as u64).min(total);
+                    let chunk = match read_slice(&blob, done, end).await {
+                        Ok(chunk) => chunk,
+                        Err(e) => return runner.finish(id, Err(e)),
+                    };
+                    if state.borrow().pending_id() != Some(id) {
+                        return;
+                    }
+                    hasher.update(&chunk);
+                    done = end;
+                    runner.progress.set(Some((done, total)));
+                }
+                let result = match job {
+                    Job::Hash => Ok(JobOutput::Hashes(hasher.finalize())),
+                    _ => Err(ToolError::Read("only hashing reads files".into())),
+                };
+                runner.finish(id, result);
+            }),
+        }
+    }
+
+    fn finish(&self, id: u32, result: Result<JobOutput, ToolError>) {
+        let state = self.state.get_value();
+        let pending = {
+            let mut state = state.borrow_mut();
+            if state.pending_id() != Some(id) {
+                return;
+            }
+            state.pending.take()
+        };
+        self.progress.set(None);
+        self.busy.set(false);
+        if let Some(pending) = pending {
+            (pending.on_done)(result);
+        }
+    }
+
+    fn spawn(&self, state: &mut State) {
+        let Ok(worker) = web_sys::Worker::new(WORKER_URL) else {
+            return;
+        };
+        let runner = *self;
+        let onmessage =
+            Closure::<dyn FnMut(web_sys::MessageEvent)>::new(move |ev: web_sys::MessageEvent| {
+                let Some(reply) = ev
+                    .data()
+                    .as_string()
+                    .and_then(|json| serde_json::from_str::<Reply>(&json).ok())
+                else {
+                    return;
+                };
+                match reply {
+                    Reply::Progress { id, done, total } => {
+                        let current = runner.state.get_value().borrow().pending_id();
+                        if current == Some(id) {
+                            runner.progress.set(Some((done, total)));
+                        }
+                    }
+                    Reply::Done { id, result } => runner.finish(id, result),
+                }
+            });
+        let onerror = Closure::<dyn FnMut()>::new(move || {
+            {
+                let state = runner.state.get_value();
+                let mut state = state.borrow_mut();
+                state.unavailable = true;
+                if let Some(worker) = state.worker.take() {
+                    worker.terminate();
+                }
+                // This handler is still running, so its closures must outlive it.
+                std::mem::forget(state.onmessage.take());
+                std::mem::forget(state.onerror.take());
+            }
+            runner.run_here();
+        });
+        worker.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        worker.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+        state.worker = Some(worker);
+        state.onmessage = Some(onmessage);
+        state.onerror = Some(onerror);
+    }
+}
+
+impl Default for JobRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn read_slice(blob: &web_sys::Blob, start: u64, end: u64) -> Result<Vec<u8>, ToolError> {
+    let error = |e: JsValue| ToolError::Read(format!("{e:?}"));
+    let slice = blob
+        .slice_with_f64_and_f64(start as f64, end as f64)
+        .map_err(error)?;
+    let buffer = wasm_bindgen_futures::JsFuture::from(slice.array_buffer())
+        .await
+        .map_err(error)?;
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
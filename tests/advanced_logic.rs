@@ -1,13 +1,13 @@
 #[cfg(test)]
 mod advanced_tests {
-    use base64::{engine::general_purpose, Engine as _};
+    use do_everything_like_a_god::tools::{development, encoding};
 
     #[test]
     fn test_large_base64() {
         let large_input = "a".repeat(1024 * 1024); // 1MB
-        let encoded = general_purpose::STANDARD.encode(large_input.as_bytes());
+        let encoded = encoding::base64_encode(large_input.as_bytes());
         assert!(encoded.len() > large_input.len());
-        let decoded = general_purpose::STANDARD.decode(encoded).unwrap();
+        let decoded = encoding::base64_decode(&encoded).unwrap();
         assert_eq!(decoded.len(), 1024 * 1024);
     }
 
@@ -18,15 +18,14 @@ mod advanced_tests {
         for _ in 0..depth {
             json_str.push_str("{\"a\":");
         }
-        json_str.push_str("1");
+        json_str.push('1');
         for _ in 0..depth {
-            json_str.push_str("}");
+            json_str.push('}');
         }
-        json_str.push_str("}");
+        json_str.push('}');
 
-        let v: serde_json::Value =
-            serde_json::from_str(&json_str).expect("Failed to parse deep JSON");
-        let prettified = serde_json::to_string_pretty(&v).unwrap();
+        let prettified =
+            development::json_format(&json_str, false).expect("Failed to parse deep JSON");
         assert!(prettified.lines().count() > depth);
     }
 
@@ -42,11 +41,11 @@ mod advanced_tests {
 
     #[test]
     fn test_regex_backtracking_limit() {
-        use regex::Regex;
         // This is a classic "evil regex" pattern, but Rust's regex engine is linear time
         // and doesn't suffer from exponential backtracking.
-        let re = Regex::new(r"(a|a)*b").unwrap();
         let input = "a".repeat(100);
-        assert!(!re.is_match(&input));
+        assert!(development::regex_find(r"(a|a)*b", &input)
+            .unwrap()
+            .is_empty());
     }
 }
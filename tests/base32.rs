@@ -0,0 +1,94 @@
+use do_everything_like_a_god::tools::encoding::{
+    base32_decode_with, base32_encode_with, Base32Alphabet,
+};
+use do_everything_like_a_god::tools::{Options, ToolError};
+
+#[test]
+fn test_encode_alphabets() {
+    let cases = [
+        (Base32Alphabet::Rfc4648, "MZXW6YQ="),
+        (Base32Alphabet::Rfc4648NoPad, "MZXW6YQ"),
+        (Base32Alphabet::Hex, "CPNMUOG="),
+        (Base32Alphabet::Crockford, "CSQPYRG"),
+        (Base32Alphabet::Z, "c3zs6ao"),
+    ];
+    for (alphabet, expected) in cases {
+        assert_eq!(
+            base32_encode_with(b"foob", alphabet),
+            expected,
+            "{alphabet:?}"
+        );
+        assert_eq!(base32_decode_with(expected, alphabet).unwrap(), b"foob");
+    }
+}
+
+#[test]
+fn test_decode_is_forgiving() {
+    // Lowercase, grouped, with or without padding.
+    let rfc = Base32Alphabet::Rfc4648;
+    assert_eq!(base32_decode_with("mzxw 6yq=", rfc).unwrap(), b"foob");
+    assert_eq!(base32_decode_with("MZXW6YQ", rfc).unwrap(), b"foob");
+    // Crockford separators and look-alikes.
+    let crockford = Base32Alphabet::Crockford;
+    assert_eq!(base32_decode_with("csqp-yrg", crockford).unwrap(), b"foob");
+    assert_eq!(
+        base32_decode_with("0O1IL", crockford).unwrap(),
+        base32_decode_with("00111", crockford).unwrap()
+    );
+}
+
+#[test]
+fn test_decode_reports_position() {
+    let error = |input, alphabet| base32_decode_with(input, alphabet).unwrap_err();
+    assert_eq!(
+        error("MZXW1YQ=", Base32Alphabet::Rfc4648),
+        ToolError::InvalidCharacter {
+            ch: '1',
+            position: 5
+        }
+    );
+    // Padding only where the alphabet has it, and only at the end.
+    assert_eq!(
+        error("MZXW6YQ=", Base32Alphabet::Rfc4648NoPad),
+        ToolError::InvalidCharacter {
+            ch: '=',
+            position: 8
+        }
+    );
+    assert_eq!(
+        error("MZ=W6YQ=", Base32Alphabet::Rfc4648),
+        ToolError::InvalidCharacter {
+            ch: 'W',
+            position: 4
+        }
+    );
+    // Positions count characters, not bytes.
+    assert_eq!(
+        error("mzé", Base32Alphabet::Z),
+        ToolError::InvalidCharacter {
+            ch: 'é',
+            position: 3
+        }
+    );
+    assert_eq!(
+        error("MZXW6Y", Base32Alphabet::Rfc4648),
+        ToolError::InvalidBase32
+    );
+}
+
+#[test]
+fn test_alphabet_from_options() {
+    assert_eq!(
+        Base32Alphabet::from_options(&Options::new()).unwrap(),
+        Base32Alphabet::Rfc4648
+    );
+    for alphabet in Base32Alphabet::ALL {
+        let options = Options::new().with("alphabet", alphabet.id());
+        assert_eq!(Base32Alphabet::from_options(&options).unwrap(), alphabet);
+    }
+    let options = Options::new().with("alphabet", "base64");
+    assert!(matches!(
+        Base32Alphabet::from_options(&options),
+        Err(ToolError::InvalidOption { .. })
+    ));
+}
@@ -0,0 +1,107 @@
+use do_everything_like_a_god::tools::encoding::{
+    base58_decode_with, base58_encode_with, base58check_decode, base58check_encode, Base58Alphabet,
+    Base58Tool,
+};
+use do_everything_like_a_god::tools::{Options, Output, Tool, ToolError};
+
+const ADDRESS: &str = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+const ADDRESS_HASH: &str = "77bff20c60e522dfaa3350c39b030a5d004e839a";
+
+fn unhex(hex: &str) -> Vec<u8> {
+    hex::decode(hex).unwrap()
+}
+
+#[test]
+fn test_alphabets() {
+    let cases = [
+        (Base58Alphabet::Bitcoin, "JxF12TrwUP45BMd"),
+        (Base58Alphabet::Flickr, "iXf12sRWto45bmC"),
+    ];
+    for (alphabet, expected) in cases {
+        assert_eq!(base58_encode_with(b"Hello World", alphabet), expected);
+        assert_eq!(
+            base58_decode_with(expected, alphabet).unwrap(),
+            b"Hello World"
+        );
+    }
+    let ripple = base58_encode_with(b"Hello World", Base58Alphabet::Ripple);
+    assert_ne!(ripple, "JxF12TrwUP45BMd");
+    assert_eq!(
+        base58_decode_with(&ripple, Base58Alphabet::Ripple).unwrap(),
+        b"Hello World"
+    );
+}
+
+#[test]
+fn test_check_decodes_addresses() {
+    let mut bitcoin = unhex("00");
+    bitcoin.extend(unhex(ADDRESS_HASH));
+    assert_eq!(
+        base58check_decode(ADDRESS, Base58Alphabet::Bitcoin).unwrap(),
+        bitcoin
+    );
+    assert_eq!(
+        base58check_encode(&bitcoin, Base58Alphabet::Bitcoin),
+        ADDRESS
+    );
+
+    // WIF private key: version 0x80.
+    let wif = base58check_decode(
+        "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ",
+        Base58Alphabet::Bitcoin,
+    )
+    .unwrap();
+    assert_eq!(wif[0], 0x80);
+    assert_eq!(
+        wif[1..],
+        unhex("0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d")
+    );
+
+    // Ripple account ID: version 0x00 in the Ripple alphabet.
+    let ripple =
+        base58check_decode("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh", Base58Alphabet::Ripple).unwrap();
+    assert_eq!(ripple[0], 0);
+    assert_eq!(
+        ripple[1..],
+        unhex("b5f762798a53d543a014caf8b297cff8f2f937e8")
+    );
+}
+
+#[test]
+fn test_check_errors() {
+    // Last character changed.
+    assert!(matches!(
+        base58check_decode(
+            "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3",
+            Base58Alphabet::Bitcoin
+        ),
+        Err(ToolError::ChecksumMismatch { .. })
+    ));
+    assert_eq!(
+        base58check_decode("2g", Base58Alphabet::Bitcoin),
+        Err(ToolError::MissingChecksum)
+    );
+    assert_eq!(
+        base58check_decode("1BvB0MSE", Base58Alphabet::Bitcoin),
+        Err(ToolError::InvalidCharacter {
+            ch: '0',
+            position: 5
+        })
+    );
+}
+
+#[test]
+fn test_tool_options() {
+    let options = Options::new()
+        .with("mode", "decode")
+        .with("checksum", "base58check");
+    assert_eq!(
+        Base58Tool.run(ADDRESS.as_bytes(), &options).unwrap(),
+        Output::Bytes([vec![0], unhex(ADDRESS_HASH)].concat())
+    );
+    let options = Options::new().with("alphabet", "flickr");
+    assert_eq!(
+        Base58Tool.run(b"Hello World", &options).unwrap(),
+        Output::Text("iXf12sRWto45bmC".into())
+    );
+}
@@ -0,0 +1,102 @@
+use do_everything_like_a_god::tools::encoding::{
+    base64_decode_lenient, base64_decode_strict, base64_encode_with, Base64Alphabet, Base64Variant,
+};
+use do_everything_like_a_god::tools::{Lang, Options, ToolError};
+
+const BYTES: &[u8] = &[0xfb, 0xff, 0xbf, 0x00];
+
+fn variant(alphabet: Base64Alphabet, padding: bool, wrap: Option<usize>) -> Base64Variant {
+    Base64Variant {
+        alphabet,
+        padding,
+        wrap,
+    }
+}
+
+#[test]
+fn test_encode_variants() {
+    let standard = Base64Variant::default();
+    assert_eq!(base64_encode_with(BYTES, &standard), "+/+/AA==");
+    let url = variant(Base64Alphabet::UrlSafe, false, None);
+    assert_eq!(base64_encode_with(BYTES, &url), "-_-_AA");
+}
+
+#[test]
+fn test_encode_wraps_lines() {
+    let data = vec![0u8; 100];
+    let pem = variant(Base64Alphabet::Standard, true, Some(64));
+    let encoded = base64_encode_with(&data, &pem);
+    let lines: Vec<&str> = encoded.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0].len(), 64);
+    assert_eq!(lines[1].len(), 64);
+    assert!(!encoded.ends_with('\n'));
+}
+
+#[test]
+fn test_variant_from_options() {
+    let options = Options::new()
+        .with("alphabet", "url-safe")
+        .with("padding", "unpadded")
+        .with("wrap", "76");
+    assert_eq!(
+        Base64Variant::from_options(&options).unwrap(),
+        variant(Base64Alphabet::UrlSafe, false, Some(76))
+    );
+    assert_eq!(
+        Base64Variant::from_options(&Options::new()).unwrap(),
+        Base64Variant::default()
+    );
+    assert!(matches!(
+        Base64Variant::from_options(&Options::new().with("wrap", "80")),
+        Err(ToolError::InvalidOption { .. })
+    ));
+}
+
+#[test]
+fn test_strict_decode_requires_the_variant() {
+    let url = variant(Base64Alphabet::UrlSafe, false, None);
+    assert_eq!(base64_decode_strict("-_-_AA", &url).unwrap(), BYTES);
+    assert!(base64_decode_strict("-_-_AA==", &url).is_err());
+    assert!(base64_decode_strict("-_-_AA", &Base64Variant::default()).is_err());
+}
+
+#[test]
+fn test_lenient_decode_detects_the_variant() {
+    let (bytes, detected) = base64_decode_lenient("-_-_AA").unwrap();
+    assert_eq!(bytes, BYTES);
+    assert_eq!(detected, variant(Base64Alphabet::UrlSafe, false, None));
+
+    let (bytes, detected) = base64_decode_lenient("  +/+/\r\n AA==\n").unwrap();
+    assert_eq!(bytes, BYTES);
+    assert_eq!(detected, variant(Base64Alphabet::Standard, true, Some(4)));
+
+    // A JWT segment: URL-safe without padding.
+    let (bytes, _) = base64_decode_lenient("eyJhbGciOiJIUzI1NiJ9").unwrap();
+    assert_eq!(bytes, br#"{"alg":"HS256"}"#);
+}
+
+#[test]
+fn test_lenient_decode_round_trips_wrapped_output() {
+    let data: Vec<u8> = (0..=255).collect();
+    let mime = variant(Base64Alphabet::Standard, true, Some(76));
+    let (bytes, detected) = base64_decode_lenient(&base64_encode_with(&data, &mime)).unwrap();
+    assert_eq!(bytes, data);
+    assert_eq!(detected, mime);
+}
+
+#[test]
+fn test_lenient_decode_rejects_mixed_alphabets() {
+    assert!(matches!(
+        base64_decode_lenient("+/-_"),
+        Err(ToolError::Decode(_))
+    ));
+}
+
+#[test]
+fn test_describe() {
+    assert_eq!(
+        variant(Base64Alphabet::UrlSafe, false, Some(76)).describe(Lang::En),
+        "URL-safe, Unpadded, wrapped at 76 columns"
+    );
+}
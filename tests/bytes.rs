@@ -0,0 +1,173 @@
+use do_everything_like_a_god::tools::bytes::{
+    as_text, hex_dump, hex_dump_with, parse_dump, parse_hex, to_hex, DumpFormat, DumpLayout,
+};
+use do_everything_like_a_god::tools::encoding;
+use do_everything_like_a_god::tools::ToolError;
+
+#[test]
+fn test_as_text_rejects_binary() {
+    assert_eq!(as_text(b"hello\tworld\r\n"), Some("hello\tworld\r\n"));
+    assert_eq!(as_text("日本語".as_bytes()), Some("日本語"));
+    assert_eq!(as_text(b""), Some(""));
+    assert_eq!(as_text(&[0xff, 0xfe]), None);
+    assert_eq!(as_text(b"nul\0byte"), None);
+}
+
+#[test]
+fn test_hex_round_trip() {
+    let data = [0x00, 0x7f, 0x80, 0xff];
+    assert_eq!(to_hex(&data), "00 7f 80 ff");
+    assert_eq!(parse_hex(&to_hex(&data)).unwrap(), data);
+    assert_eq!(to_hex(&[]), "");
+    assert_eq!(parse_hex("").unwrap(), Vec::<u8>::new());
+}
+
+#[test]
+fn test_parse_hex_accepts_common_separators() {
+    let expected = vec![0xde, 0xad, 0xbe, 0xef];
+    for input in [
+        "deadbeef",
+        "DE AD BE EF",
+        "de:ad:be:ef",
+        "0xde, 0xad, 0xbe, 0xef",
+        "de-ad\nbe-ef",
+    ] {
+        assert_eq!(parse_hex(input).unwrap(), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_parse_hex_errors() {
+    assert_eq!(
+        parse_hex("ab zz"),
+        Err(ToolError::InvalidHex("'z' at digit 3".into()))
+    );
+    assert!(matches!(parse_hex("abc"), Err(ToolError::InvalidHex(_))));
+}
+
+#[test]
+fn test_hex_dump_layout() {
+    let dump = hex_dump(b"Hello, world!\n\x00\xffmore");
+    let lines: Vec<&str> = dump.lines().collect();
+    assert_eq!(
+        lines[0],
+        "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a 00ff  Hello, world!..."
+    );
+    assert_eq!(
+        lines[1],
+        "00000010: 6d6f 7265                                more"
+    );
+    assert_eq!(hex_dump(&[]), "");
+}
+
+#[test]
+fn test_binary_survives_encoders() {
+    let data: Vec<u8> = (0..=255).collect();
+    let b64 = encoding::base64_encode(&data);
+    assert_eq!(encoding::base64_decode(&b64).unwrap(), data);
+    let b32 = encoding::base32_encode(&data);
+    assert_eq!(encoding::base32_decode(&b32).unwrap(), data);
+    let b58 = encoding::base58_encode(&data);
+    assert_eq!(encoding::base58_decode(&b58).unwrap(), data);
+    assert!(as_text(&data).is_none());
+}
+
+const SAMPLE: &[u8] = b"Hello, world!\n\x00\xffmore!";
+
+#[test]
+fn test_hex_dump_layout_options() {
+    // Matches `xxd -c 8 -g 1`.
+    let layout = DumpLayout { width: 8, group: 1 };
+    assert_eq!(
+        hex_dump_with(SAMPLE, layout),
+        "00000000: 48 65 6c 6c 6f 2c 20 77  Hello, w\n\
+         00000008: 6f 72 6c 64 21 0a 00 ff  orld!...\n\
+         00000010: 6d 6f 72 65 21           more!\n"
+    );
+    let layout = DumpLayout { width: 4, group: 4 };
+    assert_eq!(
+        hex_dump_with(b"abcdef", layout).lines().nth(1),
+        Some("00000004: 6566      ef")
+    );
+}
+
+#[test]
+fn test_parse_dump_formats() {
+    let cases = [
+        (hex_dump(SAMPLE), DumpFormat::Xxd),
+        (
+            hex_dump_with(SAMPLE, DumpLayout { width: 8, group: 1 }),
+            DumpFormat::Xxd,
+        ),
+        (
+            "0000000 6548 6c6c 2c6f 7720 726f 646c 0a21 ff00\n\
+             0000020 6f6d 6572 0021\n\
+             0000025\n"
+                .to_string(),
+            DumpFormat::Od,
+        ),
+        (
+            format!(
+                "unsigned char sample[] = {{\n  {}\n}};\nunsigned int sample_len = 20;",
+                SAMPLE
+                    .iter()
+                    .map(|b| format!("0x{b:02x}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            DumpFormat::CArray,
+        ),
+        (to_hex(SAMPLE), DumpFormat::Hex),
+    ];
+    for (dump, format) in cases {
+        assert_eq!(
+            parse_dump(&dump).unwrap(),
+            (SAMPLE.to_vec(), format),
+            "{dump}"
+        );
+    }
+}
+
+#[test]
+fn test_xxd_text_column_does_not_change_format() {
+    let inputs: [&[u8]; 4] = [
+        br#"{"a":1}"#,
+        b"hello, 0x41 world",
+        b"{ 0x01, 0x02 }, and a longer second line",
+        b"0x,{}",
+    ];
+    for input in inputs {
+        for layout in [DumpLayout::default(), DumpLayout { width: 8, group: 1 }] {
+            let dump = hex_dump_with(input, layout);
+            assert_eq!(
+                parse_dump(&dump).unwrap(),
+                (input.to_vec(), DumpFormat::Xxd),
+                "{dump}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_parse_dump_details() {
+    // `od` collapses repeated lines into `*`.
+    let od = "0000000 0000 0000 0000 0000 0000 0000 0000 0000\n*\n0000040 0000 0000 0000 0000\n0000050\n";
+    assert_eq!(parse_dump(od).unwrap().0, vec![0; 40]);
+    // C arrays may use decimal.
+    assert_eq!(parse_dump("{ 72, 0x69 }").unwrap().0, b"Hi");
+    assert_eq!(
+        parse_dump("00000000: 4865 6g6c  He.l"),
+        Err(ToolError::InvalidHex("line 1: 'g' at digit 6".into()))
+    );
+    assert!(parse_dump("{ 0x100 }").is_err());
+    assert_eq!(parse_dump("").unwrap(), (Vec::new(), DumpFormat::Hex));
+    // An offset alone is no `od` line.
+    assert_eq!(
+        parse_dump("00000000").unwrap(),
+        (vec![0, 0, 0, 0], DumpFormat::Hex)
+    );
+    assert_eq!(
+        parse_dump("01234567").unwrap(),
+        (vec![0x01, 0x23, 0x45, 0x67], DumpFormat::Hex)
+    );
+}
@@ -0,0 +1,139 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn god(args: &[&str], stdin: &[u8]) -> (i32, Vec<u8>, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_god"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    let out = child.wait_with_output().unwrap();
+    (
+        out.status.code().unwrap_or(-1),
+        out.stdout,
+        String::from_utf8_lossy(&out.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn test_cli_base64_roundtrip() {
+    let (code, out, _) = god(&["base64", "encode", "-n"], b"GOD MODE");
+    assert_eq!(code, 0);
+    assert_eq!(out, b"R09EIE1PREU=");
+
+    let (code, out, _) = god(&["base64", "decode"], b"R09EIE1PREU=\n");
+    assert_eq!(code, 0);
+    assert_eq!(out, b"GOD MODE");
+}
+
+#[test]
+fn test_cli_base64_variants() {
+    let (code, out, _) = god(&["base64", "url-safe", "unpadded", "-n"], &[0xfb, 0xff]);
+    assert_eq!(code, 0);
+    assert_eq!(out, b"-_8");
+
+    let (code, out, _) = god(&["base64", "decode"], b"-_8\n");
+    assert_eq!(code, 0);
+    assert_eq!(out, [0xfb, 0xff]);
+
+    let (code, _, _) = god(&["base64", "decode", "strict"], b"-_8\n");
+    assert_eq!(code, 1);
+}
+
+#[test]
+fn test_cli_base32_alphabets() {
+    let (code, out, _) = god(&["base32", "-n"], b"foo");
+    assert_eq!(code, 0);
+    assert_eq!(out, b"MZXW6===");
+
+    let (code, out, _) = god(&["base32", "crockford", "-n"], b"foo");
+    assert_eq!(code, 0);
+    assert_eq!(out, b"CSQPY");
+
+    let (code, out, _) = god(
+        &["base32", "decode", "--alphabet", "base32hex"],
+        b"CPNMU===\n",
+    );
+    assert_eq!(code, 0);
+    assert_eq!(out, b"foo");
+}
+
+#[test]
+fn test_cli_base58check() {
+    let (code, out, _) = god(
+        &["base58", "decode", "base58check"],
+        b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2\n",
+    );
+    assert_eq!(code, 0);
+    assert_eq!(out.len(), 21);
+    assert_eq!(out[0], 0);
+
+    let (code, _, err) = god(
+        &["base58", "decode", "base58check"],
+        b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3",
+    );
+    assert_eq!(code, 1);
+    assert!(err.starts_with("god: Checksum mismatch"));
+}
+
+#[test]
+fn test_cli_hexdump() {
+    let (code, out, _) = god(&["hexdump", "--width", "8", "--group", "1", "-n"], b"Hi!");
+    assert_eq!(code, 0);
+    assert_eq!(out, b"00000000: 48 69 21                 Hi!\n");
+
+    let (code, out, _) = god(&["hexdump", "decode"], b"0x48, 0x69, 0x21");
+    assert_eq!(code, 0);
+    assert_eq!(out, b"Hi!");
+}
+
+#[test]
+fn test_cli_hash_file() {
+    let path = std::env::temp_dir().join("god-cli-hash.bin");
+    std::fs::write(&path, b"").unwrap();
+    let (code, out, _) = god(&["hash", "--alg", "sha256", path.to_str().unwrap()], b"");
+    assert_eq!(code, 0);
+    assert_eq!(
+        out,
+        b"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855\n"
+    );
+}
+
+#[test]
+fn test_cli_jwt_sign_and_decode() {
+    let (code, token, _) = god(
+        &["jwt", "sign", "--secret", "s3cr3t", "-n"],
+        br#"{"sub":"god"}"#,
+    );
+    assert_eq!(code, 0);
+
+    let (code, out, _) = god(&["jwt", "decode", "--secret=s3cr3t"], &token);
+    assert_eq!(code, 0);
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("\"sub\": \"god\""));
+    assert!(out.contains("Signature: ✅ Signature Valid"));
+}
+
+#[test]
+fn test_cli_errors() {
+    let (code, _, err) = god(&["rot13"], b"");
+    assert_eq!(code, 2);
+    assert!(err.contains("unknown tool `rot13`"));
+
+    let (code, _, err) = god(&["json", "--alphabet", "url"], b"");
+    assert_eq!(code, 2);
+    assert!(err.contains("unknown option --alphabet"));
+
+    let (code, _, err) = god(&["base64", "--alphabet", "url"], b"");
+    assert_eq!(code, 1);
+    assert!(err.contains("Invalid value for option alphabet: url"));
+
+    let (code, _, err) = god(&["base32", "decode"], b"!!!");
+    assert_eq!(code, 1);
+    assert_eq!(err, "god: Invalid character '!' at position 1\n");
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, TimeZone, Utc};
+use do_everything_like_a_god::tools::utilities::{cron_next, CronSchedule};
+
+fn at(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+}
+
+#[test]
+fn test_every_fifteen_minutes() {
+    let runs = cron_next("*/15 * * * *", at(2024, 1, 1, 10, 7), 3).unwrap();
+    assert_eq!(
+        runs,
+        vec![
+            at(2024, 1, 1, 10, 15),
+            at(2024, 1, 1, 10, 30),
+            at(2024, 1, 1, 10, 45)
+        ]
+    );
+}
+
+#[test]
+fn test_runs_are_strictly_after_from() {
+    let runs = cron_next("0 9 * * *", at(2024, 1, 1, 9, 0), 2).unwrap();
+    assert_eq!(runs, vec![at(2024, 1, 2, 9, 0), at(2024, 1, 3, 9, 0)]);
+}
+
+#[test]
+fn test_names_and_ranges() {
+    // Weekdays at 09:30 in March; 2024-03-01 is a Friday.
+    let runs = cron_next("30 9 * mar mon-fri", at(2024, 1, 1, 0, 0), 2).unwrap();
+    assert_eq!(runs, vec![at(2024, 3, 1, 9, 30), at(2024, 3, 4, 9, 30)]);
+}
+
+#[test]
+fn test_sunday_is_zero_or_seven() {
+    let zero = cron_next("0 0 * * 0", at(2024, 1, 1, 0, 0), 3).unwrap();
+    let seven = cron_next("0 0 * * 7", at(2024, 1, 1, 0, 0), 3).unwrap();
+    assert_eq!(zero, seven);
+    assert_eq!(zero[0], at(2024, 1, 7, 0, 0));
+}
+
+#[test]
+fn test_day_of_month_or_day_of_week() {
+    // Both fields restricted: the 1st of the month or any Monday.
+    let runs = cron_next("0 0 1 * 1", at(2024, 1, 1, 12, 0), 3).unwrap();
+    assert_eq!(
+        runs,
+        vec![
+            at(2024, 1, 8, 0, 0),
+            at(2024, 1, 15, 0, 0),
+            at(2024, 1, 22, 0, 0)
+        ]
+    );
+}
+
+#[test]
+fn test_leap_day() {
+    let runs = cron_next("0 0 29 2 *", at(2024, 3, 1, 0, 0), 1).unwrap();
+    assert_eq!(runs, vec![at(2028, 2, 29, 0, 0)]);
+}
+
+#[test]
+fn test_impossible_date_gives_up() {
+    assert!(cron_next("0 0 31 2 *", at(2024, 1, 1, 0, 0), 1)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_huge_count_does_not_preallocate() {
+    assert!(cron_next("0 0 31 2 *", at(2024, 1, 1, 0, 0), usize::MAX)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_rejects_malformed_expressions() {
+    for expr in [
+        "",
+        "* * * *",
+        "60 * * * *",
+        "* 24 * * *",
+        "*/0 * * * *",
+        "5-1 * * * *",
+        "* * * foo *",
+    ] {
+        assert!(CronSchedule::parse(expr).is_err(), "{expr}");
+    }
+}
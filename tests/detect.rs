@@ -0,0 +1,70 @@
+use do_everything_like_a_god::tools::detect::{detect, Kind};
+
+fn top(input: &str) -> Kind {
+    detect(input).first().expect(input).kind
+}
+
+#[test]
+fn test_recognises_each_kind() {
+    let jwt = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJzdWIiOiIxMjM0NTY3ODkwIn0.sig";
+    assert_eq!(top(jwt), Kind::Jwt);
+    assert_eq!(top("550e8400-e29b-41d4-a716-446655440000"), Kind::Uuid);
+    assert_eq!(top(r#"{"a": [1, 2]}"#), Kind::Json);
+    assert_eq!(top("1700000000"), Kind::Timestamp);
+    assert_eq!(top("1700000000123"), Kind::Timestamp);
+    assert_eq!(top("a%20b%26c"), Kind::UrlEncoded);
+    assert_eq!(top("deadbeef00ff"), Kind::Hex);
+    assert_eq!(top("R09EIE1PREU="), Kind::Base64);
+    assert_eq!(top("2NEpo7TZRRrLZSi2U"), Kind::Base58);
+}
+
+#[test]
+fn test_previews_the_decoded_value() {
+    let base64 = detect("R09EIE1PREU=");
+    assert_eq!(base64[0].preview, "GOD MODE");
+    assert_eq!(base64[0].state.get("input").unwrap(), "GOD MODE");
+
+    let timestamp = detect("1700000000");
+    assert_eq!(timestamp[0].preview, "2023-11-14T22:13:20+00:00");
+
+    let uuid = detect("550e8400-e29b-41d4-a716-446655440000");
+    assert_eq!(uuid[0].preview, "v4 · 550e8400-e29b-41d4-a716-446655440000");
+
+    let binary = detect("AAECAw==");
+    assert_eq!(binary[0].kind, Kind::Base64);
+    assert_eq!(binary[0].preview, "00 01 02 03");
+    assert_eq!(binary[0].state.get("input-hex").unwrap(), "00 01 02 03");
+}
+
+#[test]
+fn test_ranks_ambiguous_input() {
+    // Also valid Base64, Base58 and Base32, none of which decode to text.
+    let candidates = detect("deadbeef");
+    assert_eq!(candidates[0].kind, Kind::Hex);
+    assert!(candidates.len() > 1);
+    assert!(candidates
+        .windows(2)
+        .all(|w| w[0].confidence >= w[1].confidence));
+}
+
+#[test]
+fn test_rejects_out_of_range_timestamps_and_plain_text() {
+    assert!(detect("9999999999")
+        .iter()
+        .all(|c| c.kind != Kind::Timestamp));
+    assert!(detect("").is_empty());
+    assert!(detect("hello, world!").is_empty());
+}
+
+#[test]
+fn test_links_carry_the_input() {
+    let url = detect("a%20b");
+    assert_eq!(url[0].kind.slug(), "url-escape");
+    assert_eq!(url[0].state.get("output").unwrap(), "a%20b");
+    assert_eq!(url[0].state.get("input").unwrap(), "a b");
+
+    let hex = detect("0xff10");
+    assert_eq!(hex[0].kind, Kind::Hex);
+    assert_eq!(hex[0].state.get("from").unwrap(), "16");
+    assert_eq!(hex[0].state.get("input").unwrap(), "ff10");
+}
@@ -1,15 +1,13 @@
 #[cfg(test)]
 mod edge_case_tests {
     use base64::{engine::general_purpose, Engine as _};
-    use md5::Md5;
-    use sha1::Sha1;
-    use sha2::{Digest, Sha256, Sha512};
+    use do_everything_like_a_god::tools::{crypto, development, encoding, utilities};
 
     #[test]
     fn test_base64_very_long_input() {
         let long_input = "a".repeat(100000);
-        let encoded = general_purpose::STANDARD.encode(long_input.as_bytes());
-        let decoded = general_purpose::STANDARD.decode(&encoded).unwrap();
+        let encoded = encoding::base64_encode(long_input.as_bytes());
+        let decoded = encoding::base64_decode(&encoded).unwrap();
         assert_eq!(String::from_utf8(decoded).unwrap(), long_input);
     }
 
@@ -36,9 +34,24 @@ mod edge_case_tests {
     }
 
     #[test]
-    fn test_base58_variants() {
-        use bs58::encode;
+    fn test_base32_crockford_roundtrip() {
+        use encoding::Base32Alphabet::Crockford;
+        let inputs = vec!["", "f", "fo", "foo", "foob", "fooba", "foobar"];
+        for input in inputs {
+            let encoded = encoding::base32_encode_with(input.as_bytes(), Crockford);
+            let decoded = encoding::base32_decode_with(&encoded, Crockford).unwrap();
+            assert_eq!(String::from_utf8(decoded).unwrap(), input);
+        }
+        assert_eq!(
+            encoding::base32_decode_with("!!!", Crockford)
+                .unwrap_err()
+                .to_string(),
+            "Invalid character '!' at position 1"
+        );
+    }
 
+    #[test]
+    fn test_base58_variants() {
         let test_cases = vec![
             ("Hello World", true),
             ("", true),
@@ -46,8 +59,9 @@ mod edge_case_tests {
         ];
 
         for (input, _valid) in test_cases {
-            let encoded = encode(input).into_string();
+            let encoded = encoding::base58_encode(input.as_bytes());
             assert!(!encoded.is_empty() || input.is_empty());
+            assert_eq!(encoding::base58_decode(&encoded).unwrap(), input.as_bytes());
         }
     }
 
@@ -55,47 +69,19 @@ mod edge_case_tests {
     fn test_all_hash_algorithms() {
         let input = "test input for all hashes";
 
-        let md5_result = {
-            let mut hasher = Md5::new();
-            hasher.update(input.as_bytes());
-            hex::encode(hasher.finalize())
-        };
-        assert_eq!(md5_result.len(), 32);
-
-        let sha1_result = {
-            let mut hasher = Sha1::new();
-            hasher.update(input.as_bytes());
-            hex::encode(hasher.finalize())
-        };
-        assert_eq!(sha1_result.len(), 40);
-
-        let sha256_result = {
-            let mut hasher = Sha256::new();
-            hasher.update(input.as_bytes());
-            hex::encode(hasher.finalize())
-        };
-        assert_eq!(sha256_result.len(), 64);
-
-        let sha512_result = {
-            let mut hasher = Sha512::new();
-            hasher.update(input.as_bytes());
-            hex::encode(hasher.finalize())
-        };
-        assert_eq!(sha512_result.len(), 128);
+        let results = crypto::compute_hashes(input.as_bytes());
+        let lengths: Vec<usize> = results.iter().map(|(_, digest)| digest.len()).collect();
+        assert_eq!(lengths, vec![32, 40, 64, 128, 64]);
     }
 
     #[test]
     fn test_hash_empty_string() {
         let input = "";
 
-        let mut md5 = Md5::new();
-        md5.update(input.as_bytes());
-        let md5_result = hex::encode(md5.finalize());
+        let md5_result = crypto::HashAlgorithm::Md5.digest(input.as_bytes());
         assert_eq!(md5_result, "d41d8cd98f00b204e9800998ecf8427e");
 
-        let mut sha256 = Sha256::new();
-        sha256.update(input.as_bytes());
-        let sha256_result = hex::encode(sha256.finalize());
+        let sha256_result = crypto::HashAlgorithm::Sha256.digest(input.as_bytes());
         assert_eq!(
             sha256_result,
             "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
@@ -117,12 +103,12 @@ mod edge_case_tests {
     #[test]
     fn test_json_pretty_vs_minified() {
         let minified = r#"{"name":"test","value":123}"#;
-        let parsed: serde_json::Value = serde_json::from_str(minified).unwrap();
-        let pretty = serde_json::to_string_pretty(&parsed).unwrap();
+        let pretty = development::json_format(minified, false).unwrap();
 
         assert!(pretty.contains('\n'));
         assert!(pretty.contains("  "));
         assert!(pretty.contains("name"));
+        assert_eq!(development::json_format(&pretty, true).unwrap(), minified);
     }
 
     #[test]
@@ -136,7 +122,7 @@ mod edge_case_tests {
         ];
 
         for (input, expected) in test_cases {
-            let encoded = urlencoding::encode(input);
+            let encoded = encoding::url_encode(input);
             assert_eq!(encoded, expected);
         }
     }
@@ -152,7 +138,7 @@ mod edge_case_tests {
         ];
 
         for input in test_cases {
-            let escaped = html_escape::encode_text(input).to_string();
+            let escaped = encoding::html_escape(input);
             assert!(
                 !escaped.contains('<') && !escaped.contains('>'),
                 "Input: {}",
@@ -163,8 +149,6 @@ mod edge_case_tests {
 
     #[test]
     fn test_regex_unicode_matching() {
-        use regex::Regex;
-
         let test_cases = vec![
             (r"\p{L}+", "hello", true),
             (r"\p{L}+", "Привет", true),
@@ -174,9 +158,9 @@ mod edge_case_tests {
         ];
 
         for (pattern, text, expected) in test_cases {
-            let re = Regex::new(pattern).unwrap();
+            let matches = development::regex_find(pattern, text).unwrap();
             assert_eq!(
-                re.is_match(text),
+                !matches.is_empty(),
                 expected,
                 "Pattern: {}, Text: {}",
                 pattern,
@@ -202,23 +186,29 @@ mod edge_case_tests {
 
     #[test]
     fn test_diff_operations() {
-        use similar::TextDiff;
+        use development::DiffTag;
 
         let from = "line1\nline2\nline3\nline4";
         let to = "line1\nline2 modified\nline3\nline5";
 
-        let diff = TextDiff::from_lines(from, to);
-        let changes: Vec<_> = diff.iter_all_changes().collect();
+        let changes = development::diff_lines(from, to);
 
-        assert!(changes.len() > 0);
+        assert!(!changes.is_empty());
+        assert_eq!(changes[0].tag, DiffTag::Equal);
+        assert!(changes
+            .iter()
+            .any(|c| c.tag == DiffTag::Insert && c.value == "line2 modified\n"));
+        assert!(changes
+            .iter()
+            .any(|c| c.tag == DiffTag::Delete && c.value == "line4"));
     }
 
     #[test]
     fn test_uuid_v4_format() {
         use uuid::Uuid;
 
-        let uuid = Uuid::new_v4();
-        let uuid_str = uuid.to_string();
+        let uuid_str = development::generate_uuid();
+        assert!(Uuid::parse_str(&uuid_str).is_ok());
 
         assert_eq!(uuid_str.len(), 36);
         assert_eq!(uuid_str.chars().filter(|c| *c == '-').count(), 4);
@@ -232,8 +222,12 @@ mod edge_case_tests {
         let ts = 1704067200i64;
         let dt = Utc.timestamp_opt(ts, 0).single().unwrap();
         assert!(dt.to_rfc3339().contains("2024"));
+        assert_eq!(
+            development::timestamp_to_iso(&ts.to_string()).unwrap(),
+            dt.to_rfc3339()
+        );
 
-        let now = Utc::now().timestamp();
+        let now = development::now_timestamp();
         assert!(now > 1704067200);
 
         let local = Local::now();
@@ -252,8 +246,7 @@ mod edge_case_tests {
         ];
 
         for (expr, valid) in test_cases {
-            let parts: Vec<&str> = expr.split_whitespace().collect();
-            let is_valid = parts.len() == 5;
+            let is_valid = utilities::cron_describe(expr).is_ok();
             assert_eq!(is_valid, valid, "Expression: {}", expr);
         }
     }
@@ -287,14 +280,7 @@ mod edge_case_tests {
         ];
 
         for (input, from_base, to_base, expected) in test_cases {
-            let decimal = u64::from_str_radix(input, from_base).unwrap();
-            let result = match to_base {
-                2 => format!("{:b}", decimal),
-                8 => format!("{:o}", decimal),
-                10 => format!("{}", decimal),
-                16 => format!("{:x}", decimal),
-                _ => panic!("Unsupported base"),
-            };
+            let result = development::base_convert(input, from_base, to_base).unwrap();
             assert_eq!(
                 result, expected,
                 "Input: {} from {} to {}",
@@ -312,12 +298,13 @@ mod edge_case_tests {
         ];
 
         for (data, expected_prefix) in test_cases {
-            let encoded = general_purpose::STANDARD.encode(data.as_bytes());
-            let full_data_uri = format!("data:image/png;base64,{}", encoded);
-            assert!(
-                full_data_uri.starts_with(expected_prefix)
-                    || full_data_uri.starts_with("data:image")
-            );
+            let encoded = encoding::base64_encode(data.as_bytes());
+            let mime = expected_prefix
+                .trim_start_matches("data:")
+                .trim_end_matches(";base64,");
+            let full_data_uri = utilities::image_data_uri(mime, &encoded);
+            assert!(full_data_uri.starts_with(expected_prefix));
+            assert!(full_data_uri.ends_with(&encoded));
         }
     }
 }
@@ -0,0 +1,122 @@
+use do_everything_like_a_god::tools::encoding::{
+    base36_decode, base36_encode, base45_decode, base45_encode, base62_decode, base62_encode,
+    base85_decode, base85_encode, base91_decode, base91_encode, Base85,
+};
+use do_everything_like_a_god::tools::{self, Options, Output, ToolError};
+
+#[test]
+fn test_ascii85() {
+    let cases: [(&[u8], &str); 3] = [
+        (b"Man is distinguished", "9jqo^BlbD-BleB1DJ+*+F(f,q"),
+        (&[0, 0, 0, 0, 1], "z!<"),
+        (b"hi", "BP@"),
+    ];
+    for (bytes, encoded) in cases {
+        assert_eq!(base85_encode(bytes, Base85::Ascii85), encoded);
+        assert_eq!(base85_decode(encoded, Base85::Ascii85).unwrap(), bytes);
+    }
+    // Adobe delimiters and line breaks.
+    assert_eq!(
+        base85_decode(" <~9jqo^Blb\nD-BleB1DJ+*+F(f,q~>", Base85::Ascii85).unwrap(),
+        b"Man is distinguished"
+    );
+    assert_eq!(
+        base85_decode("9jqo^v", Base85::Ascii85),
+        Err(ToolError::InvalidCharacter {
+            ch: 'v',
+            position: 6
+        })
+    );
+    assert_eq!(
+        base85_decode("9jqo^B", Base85::Ascii85),
+        Err(ToolError::InvalidLength)
+    );
+    assert_eq!(
+        base85_decode("uuuuu", Base85::Ascii85),
+        Err(ToolError::GroupOverflow { position: 1 })
+    );
+}
+
+#[test]
+fn test_z85() {
+    // The example from the Z85 specification.
+    let bytes = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+    assert_eq!(base85_encode(&bytes, Base85::Z85), "HelloWorld");
+    assert_eq!(base85_decode("HelloWorld", Base85::Z85).unwrap(), bytes);
+    // No `z` shorthand.
+    assert_eq!(base85_encode(&[0; 4], Base85::Z85), "00000");
+}
+
+#[test]
+fn test_base45() {
+    // RFC 9285 examples.
+    let cases = [
+        ("AB", "BB8"),
+        ("Hello!!", "%69 VD92EX0"),
+        ("base-45", "UJCLQE7W581"),
+        ("ietf!", "QED8WEX0"),
+    ];
+    for (text, encoded) in cases {
+        assert_eq!(base45_encode(text.as_bytes()), encoded);
+        assert_eq!(base45_decode(encoded).unwrap(), text.as_bytes());
+    }
+    assert_eq!(base45_decode("QED8WEX0\n").unwrap(), b"ietf!");
+    assert_eq!(
+        base45_decode("qED"),
+        Err(ToolError::InvalidCharacter {
+            ch: 'q',
+            position: 1
+        })
+    );
+    assert_eq!(
+        base45_decode("GGW"),
+        Err(ToolError::GroupOverflow { position: 1 })
+    );
+    assert_eq!(base45_decode("BB8B"), Err(ToolError::InvalidLength));
+}
+
+#[test]
+fn test_base36_and_base62() {
+    assert_eq!(base36_encode(b"Hello World"), "azw5bz2xp56m4qyck");
+    assert_eq!(base36_decode("AZW5BZ2XP56M4QYCK").unwrap(), b"Hello World");
+    assert_eq!(base36_encode(&[0, 0, 1, 0xff]), "00e7");
+    assert_eq!(base36_decode("00e7").unwrap(), [0, 0, 1, 0xff]);
+
+    assert_eq!(base62_encode(b"Hello World"), "73XpUgyMwkGr29M");
+    assert_eq!(base62_decode("73XpUgyMwkGr29M").unwrap(), b"Hello World");
+    assert_eq!(base62_encode(&[0, 0xff]), "047");
+    assert_eq!(
+        base62_decode("73Xp-Ugy"),
+        Err(ToolError::InvalidCharacter {
+            ch: '-',
+            position: 5
+        })
+    );
+}
+
+#[test]
+fn test_base91() {
+    assert_eq!(base91_encode(b"test"), "fPNKd");
+    assert_eq!(base91_decode("fPNKd").unwrap(), b"test");
+    assert_eq!(base91_decode("fPN\nKd").unwrap(), b"test");
+    assert_eq!(
+        base91_decode("fP Kd'"),
+        Err(ToolError::InvalidCharacter {
+            ch: '\'',
+            position: 6
+        })
+    );
+}
+
+#[test]
+fn test_tools_decode_binary() {
+    let bytes = [0u8, 0xff, 0x10, 0x80, 0];
+    for id in ["ascii85", "base45", "base36", "base62", "base91"] {
+        let tool = tools::find(id).unwrap();
+        let encoded = tool.run(&bytes, &Options::new()).unwrap().to_text();
+        let decoded = tool
+            .run(encoded.as_bytes(), &Options::new().with("mode", "decode"))
+            .unwrap();
+        assert_eq!(decoded, Output::Bytes(bytes.to_vec()), "{id}");
+    }
+}
@@ -0,0 +1,123 @@
+use do_everything_like_a_god::tools::history::{History, HistorySettings, HISTORY_LIMIT};
+use do_everything_like_a_god::tools::permalink::State;
+use do_everything_like_a_god::tools::{self, Tool};
+
+fn input(value: &str) -> State {
+    [("input".to_string(), value.to_string())].into()
+}
+
+#[test]
+fn test_record_newest_first_and_dedupe() {
+    let mut history = History::default();
+    history.record(input("a"), String::new(), 1);
+    history.record(input("b"), String::new(), 2);
+    history.record(input("a"), String::new(), 3);
+
+    let entries = history.entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].state, input("a"));
+    assert_eq!(entries[0].timestamp, 3);
+    assert_eq!(entries[1].state, input("b"));
+}
+
+#[test]
+fn test_record_keeps_latest_output() {
+    let mut history = History::default();
+    history.record(input("a"), "YQ==".to_string(), 1);
+    history.record(input("b"), "Yg==".to_string(), 2);
+    history.record(input("a"), "YWE=".to_string(), 3);
+
+    let entries = history.entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].output, "YWE=");
+    assert_eq!(entries[1].output, "Yg==");
+}
+
+#[test]
+fn test_limit_keeps_pinned_entries() {
+    let mut history = History::default();
+    history.record(input("keep"), String::new(), 0);
+    history.toggle_pin(0);
+    for i in 0..HISTORY_LIMIT + 5 {
+        history.record(input(&i.to_string()), String::new(), i as i64 + 1);
+    }
+
+    let entries = history.entries();
+    assert_eq!(entries.len(), HISTORY_LIMIT + 1);
+    assert!(entries.iter().any(|e| e.pinned && e.state == input("keep")));
+    assert_eq!(entries[0].state, input(&(HISTORY_LIMIT + 4).to_string()));
+    assert!(!entries.iter().any(|e| e.state == input("0")));
+}
+
+#[test]
+fn test_record_preserves_pin_of_existing_entry() {
+    let mut history = History::default();
+    history.record(input("a"), String::new(), 1);
+    history.toggle_pin(0);
+    history.record(input("b"), String::new(), 2);
+    history.record(input("a"), String::new(), 3);
+    assert!(history.entries()[0].pinned);
+}
+
+#[test]
+fn test_remove_and_clear() {
+    let mut history = History::default();
+    history.record(input("a"), String::new(), 1);
+    history.record(input("b"), String::new(), 2);
+    history.record(input("c"), String::new(), 3);
+    history.toggle_pin(2);
+    history.remove(0);
+    history.remove(10);
+    assert_eq!(history.entries().len(), 2);
+
+    history.clear();
+    assert_eq!(history.entries().len(), 1);
+    assert_eq!(history.entries()[0].state, input("a"));
+}
+
+#[test]
+fn test_history_json_round_trip() {
+    let mut history = History::default();
+    history.record(input("a"), "YQ==".to_string(), 1);
+    history.toggle_pin(0);
+    let json = serde_json::to_string(&history).unwrap();
+    assert_eq!(serde_json::from_str::<History>(&json).unwrap(), history);
+}
+
+#[test]
+fn test_settings_are_opt_in_and_exclude_sensitive_tools() {
+    let base64: &dyn Tool = tools::find("base64").unwrap();
+    let aes: &dyn Tool = tools::find("aes").unwrap();
+    let jwt: &dyn Tool = tools::find("jwt").unwrap();
+
+    let mut settings = HistorySettings::default();
+    assert!(!settings.is_enabled(base64));
+
+    settings.enabled = true;
+    assert!(settings.is_enabled(base64));
+    assert!(!settings.is_enabled(aes));
+    assert!(!settings.is_enabled(jwt));
+
+    settings.set_enabled(aes, true);
+    settings.set_enabled(base64, false);
+    assert!(settings.is_enabled(aes));
+    assert!(!settings.is_enabled(base64));
+}
+
+#[test]
+fn test_merge_combines_and_orders_entries() {
+    let mut history = History::default();
+    history.record(input("a"), String::new(), 1);
+    history.record(input("b"), String::new(), 3);
+
+    let mut other = History::default();
+    other.record(input("a"), String::new(), 4);
+    other.toggle_pin(0);
+    other.record(input("c"), String::new(), 2);
+
+    history.merge(other);
+    let states: Vec<_> = history.entries().iter().map(|e| e.state.clone()).collect();
+    assert_eq!(states, vec![input("a"), input("b"), input("c")]);
+    assert_eq!(history.entries()[0].timestamp, 4);
+    assert!(history.entries()[0].pinned);
+}
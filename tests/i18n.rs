@@ -0,0 +1,65 @@
+use do_everything_like_a_god::i18n::{missing_messages, Lang};
+use do_everything_like_a_god::tools::{self, ToolError};
+
+#[test]
+fn test_catalogs_are_complete() {
+    for lang in Lang::ALL {
+        assert!(
+            missing_messages(lang).is_empty(),
+            "{} is missing {:?}",
+            lang.code(),
+            missing_messages(lang)
+        );
+    }
+}
+
+#[test]
+fn test_negotiate_language_tags() {
+    assert_eq!(Lang::negotiate("zh-TW"), Lang::Zh);
+    assert_eq!(Lang::negotiate("zh-Hant-HK"), Lang::Zh);
+    assert_eq!(Lang::negotiate("zh"), Lang::ZhHans);
+    assert_eq!(Lang::negotiate("zh_CN"), Lang::ZhHans);
+    assert_eq!(Lang::negotiate("ja-JP"), Lang::Ja);
+    assert_eq!(Lang::negotiate("fr-FR"), Lang::En);
+    assert_eq!(Lang::negotiate(""), Lang::En);
+}
+
+#[test]
+fn test_code_round_trip() {
+    for lang in Lang::ALL {
+        assert_eq!(Lang::from_code(lang.code()), Some(lang));
+    }
+    assert_eq!(Lang::from_code("xx"), None);
+}
+
+#[test]
+fn test_format_fills_placeholders() {
+    assert_eq!(
+        Lang::En.format("error.unknown-tool", &[("id", "rot13")]),
+        "Unknown tool: rot13"
+    );
+    assert_eq!(Lang::En.t("no.such.id"), "no.such.id");
+}
+
+#[test]
+fn test_errors_are_localized() {
+    let err = ToolError::UnknownTool("rot13".to_string());
+    assert_eq!(err.to_string(), err.localized(Lang::En));
+    assert_eq!(err.localized(Lang::Ja), "不明なツール: rot13");
+    assert_ne!(err.localized(Lang::ZhHans), err.to_string());
+}
+
+#[test]
+fn test_every_tool_is_named_in_every_language() {
+    for lang in Lang::ALL {
+        for tool in tools::all() {
+            assert_ne!(
+                tool.name(lang),
+                tool.id(),
+                "{} in {}",
+                tool.id(),
+                lang.code()
+            );
+        }
+    }
+}
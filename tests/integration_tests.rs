@@ -1,14 +1,13 @@
 #[cfg(test)]
 mod integration_tests {
     use base64::{engine::general_purpose, Engine as _};
-    use md5::Md5;
-    use sha2::Digest;
+    use do_everything_like_a_god::tools::{crypto, development, encoding};
 
     #[test]
     fn test_base64_empty_input() {
-        let encoded = general_purpose::STANDARD.encode(b"");
+        let encoded = encoding::base64_encode(b"");
         assert_eq!(encoded, "");
-        let decoded = general_purpose::STANDARD.decode("").unwrap();
+        let decoded = encoding::base64_decode("").unwrap();
         assert!(decoded.is_empty());
     }
 
@@ -16,8 +15,8 @@ mod integration_tests {
     fn test_base64_unicode() {
         let inputs = vec!["你好世界", "🎉🚀🔥", "日本語テスト", "العربية"];
         for input in inputs {
-            let encoded = general_purpose::STANDARD.encode(input.as_bytes());
-            let decoded = general_purpose::STANDARD.decode(&encoded).unwrap();
+            let encoded = encoding::base64_encode(input.as_bytes());
+            let decoded = encoding::base64_decode(&encoded).unwrap();
             assert_eq!(String::from_utf8(decoded).unwrap(), input);
         }
     }
@@ -26,13 +25,8 @@ mod integration_tests {
     fn test_hash_consistency() {
         let input = "test input for hash consistency check";
 
-        let mut md5 = Md5::new();
-        md5.update(input);
-        let md5_result = hex::encode(md5.finalize());
-
-        let mut md5_2 = Md5::new();
-        md5_2.update(input);
-        let md5_result_2 = hex::encode(md5_2.finalize());
+        let md5_result = crypto::HashAlgorithm::Md5.digest(input.as_bytes());
+        let md5_result_2 = crypto::HashAlgorithm::Md5.digest(input.as_bytes());
 
         assert_eq!(md5_result, md5_result_2);
         assert_eq!(md5_result.len(), 32);
@@ -51,8 +45,7 @@ mod integration_tests {
         ];
 
         for (input, expected) in cases {
-            let v: serde_json::Value = serde_json::from_str(input).unwrap();
-            let output = serde_json::to_string(&v).unwrap();
+            let output = development::json_format(input, true).unwrap();
             assert_eq!(output, expected);
         }
     }
@@ -60,8 +53,8 @@ mod integration_tests {
     #[test]
     fn test_url_special_chars() {
         let special = "!@#$%^&*()+=[]{}|;':\",./<>?";
-        let encoded = urlencoding::encode(special);
-        let decoded = urlencoding::decode(&encoded).unwrap();
+        let encoded = encoding::url_encode(special);
+        let decoded = encoding::url_decode(&encoded).unwrap();
         assert_eq!(decoded, special);
     }
 
@@ -73,22 +66,55 @@ mod integration_tests {
         let p_b64 = general_purpose::URL_SAFE_NO_PAD.encode(payload);
         let token = format!("{}.{}.signature", h_b64, p_b64);
 
-        let parts: Vec<&str> = token.split('.').collect();
-        assert_eq!(parts.len(), 3);
+        let decoded = crypto::jwt_decode(&token, "").unwrap();
+        assert!(decoded.header.contains("HS256"));
+        assert!(decoded.payload.unwrap().contains("test"));
+        assert_eq!(decoded.signature, None);
+
+        let decoded = crypto::jwt_decode(&token, "secret").unwrap();
+        assert_eq!(decoded.signature, Some(crypto::SignatureStatus::Invalid));
+
+        let signed = format!(
+            "{}.{}.{}",
+            h_b64,
+            p_b64,
+            crypto::jwt_sign(&format!("{}.{}", h_b64, p_b64), "secret")
+        );
+        let decoded = crypto::jwt_decode(&signed, "secret").unwrap();
+        assert_eq!(decoded.signature, Some(crypto::SignatureStatus::Valid));
+    }
 
-        let h_dec =
-            String::from_utf8(general_purpose::URL_SAFE_NO_PAD.decode(parts[0]).unwrap()).unwrap();
-        assert!(h_dec.contains("HS256"));
+    #[test]
+    fn test_jwt_invalid_format() {
+        assert_eq!(
+            crypto::jwt_decode("not-a-jwt", "").unwrap_err().to_string(),
+            "Invalid JWT format"
+        );
+        assert!(crypto::jwt_decode("@@@.e30", "")
+            .unwrap_err()
+            .to_string()
+            .starts_with("Header: Base64 Error"));
+    }
 
-        let p_dec =
-            String::from_utf8(general_purpose::URL_SAFE_NO_PAD.decode(parts[1]).unwrap()).unwrap();
-        assert!(p_dec.contains("test"));
+    #[test]
+    fn test_aes_roundtrip() {
+        let key = crypto::aes_generate_key();
+        let encrypted = crypto::aes_encrypt(&key, "secret message".as_bytes()).unwrap();
+        let decrypted = crypto::aes_decrypt(&key, &encrypted).unwrap();
+        assert_eq!(decrypted, b"secret message");
+
+        assert_eq!(
+            crypto::aes_encrypt("abcd", b"x").unwrap_err().to_string(),
+            "Key must be 32 bytes (64 hex chars)"
+        );
+        assert!(crypto::aes_decrypt(&key, "zz")
+            .unwrap_err()
+            .to_string()
+            .starts_with("Invalid hex"));
     }
 
     #[test]
     fn test_regex_special_patterns() {
-        use regex::Regex;
-
         let test_cases = vec![
             (r"^\d+$", "12345", true),
             (r"^\d+$", "12a45", false),
@@ -107,9 +133,9 @@ mod integration_tests {
         ];
 
         for (pattern, text, should_match) in test_cases {
-            let re = Regex::new(pattern).unwrap();
+            let matches = development::regex_find(pattern, text).unwrap();
             assert_eq!(
-                re.is_match(text),
+                !matches.is_empty(),
                 should_match,
                 "Pattern: {}, Text: {}",
                 pattern,
@@ -120,18 +146,13 @@ mod integration_tests {
 
     #[test]
     fn test_diff_empty_inputs() {
-        use similar::TextDiff;
-
-        let diff1 = TextDiff::from_lines("", "new content");
-        let changes: Vec<_> = diff1.iter_all_changes().collect();
+        let changes = development::diff_lines("", "new content");
         assert!(!changes.is_empty());
 
-        let diff2 = TextDiff::from_lines("old content", "");
-        let changes: Vec<_> = diff2.iter_all_changes().collect();
+        let changes = development::diff_lines("old content", "");
         assert!(!changes.is_empty());
 
-        let diff3 = TextDiff::from_lines("", "");
-        let changes: Vec<_> = diff3.iter_all_changes().collect();
+        let changes = development::diff_lines("", "");
         assert!(changes.is_empty());
     }
 
@@ -147,20 +168,33 @@ mod integration_tests {
         ];
 
         for (input, from_base, expected_dec) in test_cases {
-            let result = u128::from_str_radix(input, from_base).unwrap();
-            assert_eq!(format!("{}", result), expected_dec);
+            let result = development::base_convert(input, from_base, 10).unwrap();
+            assert_eq!(result, expected_dec);
         }
+        assert_eq!(
+            development::base_convert("12", 2, 10)
+                .unwrap_err()
+                .to_string(),
+            "Error"
+        );
     }
 
     #[test]
     fn test_timestamp_edge_cases() {
-        use chrono::{TimeZone, Utc};
-
-        let epoch = Utc.timestamp_opt(0, 0).single().unwrap();
-        assert_eq!(epoch.to_rfc3339(), "1970-01-01T00:00:00+00:00");
-
-        let negative = Utc.timestamp_opt(-1, 0).single().unwrap();
-        assert_eq!(negative.to_rfc3339(), "1969-12-31T23:59:59+00:00");
+        assert_eq!(
+            development::timestamp_to_iso("0").unwrap(),
+            "1970-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            development::timestamp_to_iso("-1").unwrap(),
+            "1969-12-31T23:59:59+00:00"
+        );
+        assert_eq!(
+            development::timestamp_to_iso("abc")
+                .unwrap_err()
+                .to_string(),
+            "Invalid"
+        );
     }
 
     #[test]
@@ -173,7 +207,7 @@ mod integration_tests {
         ];
 
         for vector in xss_vectors {
-            let escaped = html_escape::encode_safe(vector).to_string();
+            let escaped = encoding::html_escape(vector);
             assert!(!escaped.contains('<'), "Failed to escape < in: {}", vector);
             assert!(!escaped.contains('>'), "Failed to escape > in: {}", vector);
         }
@@ -0,0 +1,160 @@
+use do_everything_like_a_god::tools::crypto::{self, MultiHasher};
+use do_everything_like_a_god::tools::development::DiffTag;
+use do_everything_like_a_god::tools::job::{
+    self, Job, JobOutput, Reply, Request, Source, CHUNK_SIZE,
+};
+use do_everything_like_a_god::tools::ToolError;
+
+#[test]
+fn test_multi_hasher_matches_one_shot_hashes() {
+    let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+    let mut hasher = MultiHasher::default();
+    for chunk in data.chunks(777) {
+        hasher.update(chunk);
+    }
+    assert_eq!(hasher.finalize(), crypto::compute_hashes(&data));
+}
+
+#[test]
+fn test_hash_job_reports_progress_per_chunk() {
+    let data = vec![7u8; CHUNK_SIZE * 2 + 10];
+    let mut reports = Vec::new();
+    let output = job::run(&Job::Hash, &data, &mut |done, total| {
+        reports.push((done, total))
+    });
+    assert_eq!(output, Ok(JobOutput::Hashes(crypto::compute_hashes(&data))));
+    let total = data.len() as u64;
+    assert_eq!(
+        reports,
+        vec![
+            (CHUNK_SIZE as u64, total),
+            (CHUNK_SIZE as u64 * 2, total),
+            (total, total)
+        ]
+    );
+}
+
+#[test]
+fn test_hash_job_on_empty_input() {
+    let output = job::run(&Job::Hash, &[], &mut |_, _| panic!("no progress expected"));
+    assert_eq!(output, Ok(JobOutput::Hashes(crypto::compute_hashes(b""))));
+}
+
+#[test]
+fn test_regex_and_diff_jobs() {
+    let regex = Job::Regex {
+        pattern: r"\d+".into(),
+        text: "a1 b22".into(),
+    };
+    assert_eq!(
+        job::run(&regex, &[], &mut |_, _| {}),
+        Ok(JobOutput::Matches(vec!["1".into(), "22".into()]))
+    );
+
+    let invalid = Job::Regex {
+        pattern: "(".into(),
+        text: String::new(),
+    };
+    assert!(matches!(
+        job::run(&invalid, &[], &mut |_, _| {}),
+        Err(ToolError::Regex(_))
+    ));
+
+    let diff = Job::Diff {
+        old: "a\nb\n".into(),
+        new: "a\nc\n".into(),
+    };
+    let Ok(JobOutput::Diff(lines)) = job::run(&diff, &[], &mut |_, _| {}) else {
+        panic!("expected a diff");
+    };
+    let tags: Vec<DiffTag> = lines.iter().map(|l| l.tag).collect();
+    assert_eq!(tags, [DiffTag::Equal, DiffTag::Delete, DiffTag::Insert]);
+}
+
+#[test]
+fn test_messages_round_trip_through_json() {
+    let request = Request {
+        id: 3,
+        job: Job::Diff {
+            old: "x".into(),
+            new: "y".into(),
+        },
+    };
+    let json = serde_json::to_string(&request).unwrap();
+    assert_eq!(serde_json::from_str::<Request>(&json).unwrap(), request);
+
+    for reply in [
+        Reply::Progress {
+            id: 3,
+            done: 1,
+            total: 2,
+        },
+        Reply::Done {
+            id: 3,
+            result: Err(ToolError::InvalidCron),
+        },
+    ] {
+        let json = serde_json::to_string(&reply).unwrap();
+        let parsed: Reply = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.id(), 3);
+        assert_eq!(parsed, reply);
+    }
+}
+
+/// Hands out at most `max` bytes per read, like a short read from a file.
+struct ShortReads<'a> {
+    data: &'a [u8],
+    max: usize,
+    reads: usize,
+}
+
+impl Source for ShortReads<'_> {
+    fn size(&self) -> u64 {
+        self.data.len() as u64
+    }
+
+    fn read(&mut self, offset: u64, len: usize) -> Result<Vec<u8>, ToolError> {
+        self.reads += 1;
+        let start = offset as usize;
+        let end = (start + len.min(self.max)).min(self.data.len());
+        Ok(self.data[start..end].to_vec())
+    }
+}
+
+#[test]
+fn test_run_from_streams_short_reads() {
+    let data: Vec<u8> = (0..5000u32).map(|i| i as u8).collect();
+    let mut source = ShortReads {
+        data: &data,
+        max: 1000,
+        reads: 0,
+    };
+    let output = job::run_from(&Job::Hash, &mut source, &mut |_, _| {});
+    assert_eq!(output, Ok(JobOutput::Hashes(crypto::compute_hashes(&data))));
+    assert_eq!(source.reads, 5);
+}
+
+#[test]
+fn test_run_from_reports_truncated_source() {
+    struct Truncated;
+    impl Source for Truncated {
+        fn size(&self) -> u64 {
+            10
+        }
+        fn read(&mut self, _: u64, _: usize) -> Result<Vec<u8>, ToolError> {
+            Ok(Vec::new())
+        }
+    }
+    assert!(matches!(
+        job::run_from(&Job::Hash, &mut Truncated, &mut |_, _| {}),
+        Err(ToolError::Read(_))
+    ));
+}
+
+#[test]
+fn test_format_size() {
+    assert_eq!(job::format_size(0.0), "0 B");
+    assert_eq!(job::format_size(1023.0), "1023 B");
+    assert_eq!(job::format_size(1536.0), "1.5 KiB");
+    assert_eq!(job::format_size(3.0 * 1024.0 * 1024.0 * 1024.0), "3.0 GiB");
+}
@@ -0,0 +1,57 @@
+use do_everything_like_a_god::tools::permalink::{self, State};
+use do_everything_like_a_god::tools::ToolError;
+
+fn state(pairs: &[(&str, &str)]) -> State {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_short_state_stays_uncompressed() {
+    let s = state(&[("input", "0 0 * * 1")]);
+    let fragment = permalink::encode(&s);
+    assert!(fragment.starts_with("j="), "{}", fragment);
+    assert_eq!(permalink::decode(&fragment).unwrap(), s);
+}
+
+#[test]
+fn test_long_state_is_deflated() {
+    let s = state(&[
+        ("pattern", "[a-z]+"),
+        ("input", &"lorem ipsum dolor sit amet ".repeat(50)),
+    ]);
+    let fragment = permalink::encode(&s);
+    assert!(fragment.starts_with("z="), "{}", fragment);
+    assert!(fragment.len() < 200);
+    assert_eq!(permalink::decode(&format!("#{}", fragment)).unwrap(), s);
+}
+
+#[test]
+fn test_unicode_round_trip() {
+    let s = state(&[("input", "上帝模式 🚀"), ("output", "5LiK5bid")]);
+    assert_eq!(permalink::decode(&permalink::encode(&s)).unwrap(), s);
+}
+
+#[test]
+fn test_decode_rejects_foreign_fragments() {
+    assert!(matches!(
+        permalink::decode("#section-2"),
+        Err(ToolError::Decode(_))
+    ));
+    assert!(matches!(permalink::decode(""), Err(ToolError::Decode(_))));
+    assert!(matches!(
+        permalink::decode("j=!!!"),
+        Err(ToolError::Decode(_))
+    ));
+    assert!(matches!(
+        permalink::decode("z=bm90IGRlZmxhdGU"),
+        Err(ToolError::Decode(_))
+    ));
+    // base64url of `[1,2]`
+    assert!(matches!(
+        permalink::decode("j=WzEsMl0"),
+        Err(ToolError::Json(_))
+    ));
+}
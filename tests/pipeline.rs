@@ -0,0 +1,116 @@
+use do_everything_like_a_god::tools::pipeline::{Recipe, Step, RECIPE_VERSION};
+use do_everything_like_a_god::tools::{Options, Output, ToolError};
+
+fn decode(tool: &str) -> Step {
+    Step::new(tool, Options::new().with("mode", "decode"))
+}
+
+#[test]
+fn test_url_base64_json_chain() {
+    let recipe = Recipe::new(
+        "jwt-ish",
+        vec![
+            decode("url-escape"),
+            decode("base64"),
+            Step::new("json", Options::new().with("style", "minify")),
+        ],
+    );
+    // base64 of `{ "a": 1 }` with the padding percent-encoded
+    let results = recipe.run(b"eyAiYSI6IDEgfQ%3D%3D");
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results[0].as_ref().unwrap(),
+        &Output::Text("eyAiYSI6IDEgfQ==".into())
+    );
+    assert_eq!(
+        results[1].as_ref().unwrap(),
+        &Output::Bytes(b"{ \"a\": 1 }".to_vec())
+    );
+    assert_eq!(results[2].as_ref().unwrap().to_text(), "{\"a\":1}");
+}
+
+#[test]
+fn test_run_stops_after_first_error() {
+    let recipe = Recipe::new(
+        "",
+        vec![decode("base64"), Step::new("json", Options::new())],
+    );
+    let results = recipe.run(b"!!!not base64!!!");
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results[0], Err(ToolError::Decode(_))));
+}
+
+#[test]
+fn test_empty_recipe_has_no_results() {
+    assert!(Recipe::default().run(b"anything").is_empty());
+}
+
+#[test]
+fn test_json_round_trip() {
+    let recipe = Recipe::new(
+        "hash it",
+        vec![
+            Step::new("base64", Options::new()),
+            Step::new("hash", Options::new().with("alg", "sha256")),
+        ],
+    );
+    let json = recipe.to_json();
+    assert!(json.contains("\"version\": 1"));
+    assert_eq!(Recipe::from_json(&json).unwrap(), recipe);
+}
+
+#[test]
+fn test_from_json_defaults_missing_fields() {
+    let recipe = Recipe::from_json(r#"{"version":1,"steps":[{"tool":"uuid"}]}"#).unwrap();
+    assert_eq!(recipe.name, "");
+    assert_eq!(recipe.steps, vec![Step::new("uuid", Options::new())]);
+}
+
+#[test]
+fn test_validate_rejects_bad_recipes() {
+    let err = Recipe::from_json(r#"{"version":1,"steps":[{"tool":"rot13"}]}"#).unwrap_err();
+    assert_eq!(err, ToolError::UnknownTool("rot13".into()));
+
+    let err = Recipe::from_json(
+        r#"{"version":1,"steps":[{"tool":"base64","options":{"mode":"rot13"}}]}"#,
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Invalid value for option mode: rot13");
+
+    let err =
+        Recipe::from_json(r#"{"version":1,"steps":[{"tool":"base64","options":{"shift":"3"}}]}"#)
+            .unwrap_err();
+    assert!(matches!(err, ToolError::InvalidOption { ref key, .. } if key == "shift"));
+
+    let newer = format!(r#"{{"version":{},"steps":[]}}"#, RECIPE_VERSION + 1);
+    assert_eq!(
+        Recipe::from_json(&newer).unwrap_err(),
+        ToolError::UnsupportedVersion(RECIPE_VERSION + 1)
+    );
+
+    assert!(matches!(
+        Recipe::from_json("not json"),
+        Err(ToolError::Json(_))
+    ));
+}
+
+#[test]
+fn test_without_secrets_drops_keys() {
+    let recipe = Recipe::new(
+        "secret",
+        vec![
+            Step::new(
+                "aes",
+                Options::new().with("mode", "decrypt").with("key", "00ff"),
+            ),
+            Step::new("jwt", Options::new().with("secret", "hunter2")),
+        ],
+    );
+    let shared = recipe.without_secrets();
+    assert_eq!(
+        shared.steps[0].options,
+        Options::new().with("mode", "decrypt")
+    );
+    assert_eq!(shared.steps[1].options, Options::new());
+    assert!(!shared.to_json().contains("hunter2"));
+}
@@ -0,0 +1,63 @@
+use do_everything_like_a_god::precache::{inject, Precache};
+
+fn files<'a>(list: &'a [(&'a str, &'a str)]) -> impl Iterator<Item = (&'a str, &'a [u8])> {
+    list.iter().map(|(p, c)| (*p, c.as_bytes()))
+}
+
+#[test]
+fn test_lists_root_first_and_skips_generated_files() {
+    let precache = Precache::new(files(&[
+        ("index.html", "<html>"),
+        ("sw.js", "worker"),
+        ("robots.txt", "robots"),
+        ("sitemap.xml", "<urlset>"),
+        ("app-1234.wasm", "wasm"),
+        ("base64/index.html", "<html>"),
+    ]));
+    assert_eq!(
+        precache.assets,
+        vec!["./", "app-1234.wasm", "base64/index.html", "index.html"]
+    );
+}
+
+#[test]
+fn test_version_follows_content_not_order() {
+    let a = Precache::new(files(&[("index.html", "a"), ("app.js", "b")]));
+    let b = Precache::new(files(&[("app.js", "b"), ("index.html", "a")]));
+    let c = Precache::new(files(&[("index.html", "a"), ("app.js", "c")]));
+    assert_eq!(a.version, b.version);
+    assert_ne!(a.version, c.version);
+}
+
+#[test]
+fn test_version_ignores_skipped_files() {
+    let a = Precache::new(files(&[("index.html", "a"), ("sw.js", "1")]));
+    let b = Precache::new(files(&[("index.html", "a"), ("sw.js", "2")]));
+    assert_eq!(a.version, b.version);
+}
+
+#[test]
+fn test_inject_replaces_only_the_placeholder_line() {
+    let template = "// head\nconst PRECACHE = { \"version\": \"dev\", \"assets\": [\"./\"] };\nconst CACHE_NAME = 1;\n";
+    let precache = Precache::new(files(&[("index.html", "a")]));
+    let sw = inject(template, &precache).unwrap();
+    let lines: Vec<&str> = sw.lines().collect();
+    assert_eq!(lines[0], "// head");
+    assert_eq!(
+        lines[1],
+        format!(
+            "const PRECACHE = {{\"version\":\"{}\",\"assets\":[\"./\",\"index.html\"]}};",
+            precache.version
+        )
+    );
+    assert_eq!(lines[2], "const CACHE_NAME = 1;");
+}
+
+#[test]
+fn test_inject_needs_the_placeholder() {
+    let precache = Precache::new(files(&[]));
+    assert_eq!(
+        inject("self.addEventListener('fetch', f);\n", &precache),
+        None
+    );
+}
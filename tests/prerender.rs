@@ -0,0 +1,74 @@
+use do_everything_like_a_god::i18n::Lang;
+use do_everything_like_a_god::prerender::fill;
+
+const TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="zh-TW">
+    <head>
+        <title>Old</title>
+        <meta name="description" content="old"/>
+        <meta name="robots" content="index, follow"/>
+        <meta property="og:title" content="old"/>
+        <meta property="og:type" content="website"/>
+        <link rel="canonical" href="https://example.com/"/>
+        <link rel="icon" href="data:image/svg+xml,<svg viewBox='0 0 1 1'><text>x</text></svg>"/>
+    </head>
+    <body></body>
+</html>
+"#;
+
+const HEAD: &str = r#"<title>New</title><meta name="description" content="new"/>"#;
+
+#[test]
+fn test_fill_replaces_page_meta_lang_and_body() {
+    let html = fill(TEMPLATE, HEAD, "<main>hi</main>", Lang::Ja).unwrap();
+    assert!(html.contains(r#"<html lang="ja">"#));
+    assert!(html.contains("<title>New</title>"));
+    assert!(!html.contains("Old"));
+    assert!(!html.contains(r#"content="old""#));
+    assert!(!html.contains("canonical"));
+    assert!(html.contains(r#"<meta name="robots" content="index, follow"/>"#));
+    assert!(html.contains(r#"<meta property="og:type" content="website"/>"#));
+    assert!(html.contains("<text>x</text></svg>\"/>"));
+    assert!(html.contains("<body><main>hi</main></body>"));
+}
+
+#[test]
+fn test_fill_is_idempotent() {
+    let once = fill(TEMPLATE, HEAD, "<main>hi</main>", Lang::En).unwrap();
+    let twice = fill(&once, HEAD, "<main>hi</main>", Lang::En).unwrap();
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn test_fill_needs_head_and_body() {
+    assert_eq!(fill("<html><body></body></html>", HEAD, "", Lang::En), None);
+    assert_eq!(fill("<html><head></head></html>", HEAD, "", Lang::En), None);
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn test_render_localizes_the_route() {
+    use do_everything_like_a_god::{prerender::render, registry};
+
+    let (head, body) = render("https://example.com/app/", registry::find("hash"), Lang::Ja);
+    assert!(head.contains("<title>ハッシュ生成 | 神のようにすべてをこなす</title>"));
+    assert!(head.contains(r#"href="https://example.com/app/hash""#));
+    assert!(body.contains("tool-container"));
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn test_every_registry_page_has_a_route() {
+    use do_everything_like_a_god::{prerender::render, registry};
+
+    let (_, home) = render("https://example.com/app/", None, Lang::En);
+    assert!(home.contains(r#"class="hero""#));
+    for page in registry::pages() {
+        let (_, body) = render("https://example.com/app/", Some(page), Lang::En);
+        assert!(
+            !body.contains(r#"class="hero""#),
+            "{} renders the home page",
+            page.slug
+        );
+    }
+}
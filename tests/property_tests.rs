@@ -1,28 +1,79 @@
 #![cfg(not(target_arch = "wasm32"))]
 
-use base64::{engine::general_purpose, Engine as _};
+use do_everything_like_a_god::tools::{encoding, permalink};
 use proptest::prelude::*;
 
 proptest! {
     #[test]
     fn test_base64_roundtrip(s in "\\PC*") {
-        let encoded = general_purpose::STANDARD.encode(s.as_bytes());
-        let decoded = general_purpose::STANDARD.decode(encoded).unwrap();
+        let encoded = encoding::base64_encode(s.as_bytes());
+        let decoded = encoding::base64_decode(&encoded).unwrap();
         let decoded_str = String::from_utf8(decoded).unwrap();
         prop_assert_eq!(s, decoded_str);
     }
 
     #[test]
     fn test_html_escape_roundtrip(s in "\\PC*") {
-        let escaped = html_escape::encode_safe(&s).to_string();
-        let unescaped = html_escape::decode_html_entities(&escaped).to_string();
+        let escaped = encoding::html_escape(&s);
+        let unescaped = encoding::html_unescape(&escaped);
         prop_assert_eq!(s, unescaped);
     }
 
     #[test]
     fn test_url_escape_roundtrip(s in "\\PC*") {
-        let encoded = urlencoding::encode(&s).to_string();
-        let decoded = urlencoding::decode(&encoded).unwrap();
-        prop_assert_eq!(s, decoded.into_owned());
+        let encoded = encoding::url_encode(&s);
+        let decoded = encoding::url_decode(&encoded).unwrap();
+        prop_assert_eq!(s, decoded);
+    }
+
+    #[test]
+    fn test_base32_roundtrip(data in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let encoded = encoding::base32_encode(&data);
+        prop_assert_eq!(encoding::base32_decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn test_base58_roundtrip(data in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let encoded = encoding::base58_encode(&data);
+        prop_assert_eq!(encoding::base58_decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn test_ascii85_roundtrip(data in proptest::collection::vec(any::<u8>(), 0..256)) {
+        for variant in [encoding::Base85::Ascii85, encoding::Base85::Z85] {
+            let encoded = encoding::base85_encode(&data, variant);
+            prop_assert_eq!(&encoding::base85_decode(&encoded, variant).unwrap(), &data);
+        }
+    }
+
+    #[test]
+    fn test_base45_roundtrip(data in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let encoded = encoding::base45_encode(&data);
+        prop_assert_eq!(encoding::base45_decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn test_base36_roundtrip(data in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let encoded = encoding::base36_encode(&data);
+        prop_assert_eq!(encoding::base36_decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn test_base62_roundtrip(data in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let encoded = encoding::base62_encode(&data);
+        prop_assert_eq!(encoding::base62_decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn test_base91_roundtrip(data in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let encoded = encoding::base91_encode(&data);
+        prop_assert_eq!(encoding::base91_decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn test_permalink_roundtrip(state in proptest::collection::btree_map("[a-z]{1,8}", "\\PC*", 0..4)) {
+        let fragment = permalink::encode(&state);
+        prop_assert!(fragment.bytes().all(|b| b.is_ascii_alphanumeric() || b"-_=".contains(&b)));
+        prop_assert_eq!(permalink::decode(&fragment).unwrap(), state);
     }
 }
@@ -0,0 +1,118 @@
+use do_everything_like_a_god::i18n::Lang;
+use do_everything_like_a_god::registry::{self, Category};
+use do_everything_like_a_god::tools;
+use std::collections::HashSet;
+
+const SITE: &str = "https://example.com/app/";
+
+#[test]
+fn test_slugs_are_unique() {
+    let slugs: HashSet<_> = registry::pages().iter().map(|p| p.slug).collect();
+    assert_eq!(slugs.len(), registry::pages().len());
+}
+
+#[test]
+fn test_every_tool_has_a_page() {
+    for tool in tools::all() {
+        assert!(registry::find(tool.id()).is_some(), "{}", tool.id());
+    }
+    assert!(registry::find("pipeline").is_some());
+    assert!(registry::find("nope").is_none());
+}
+
+#[test]
+fn test_every_category_has_pages() {
+    for category in Category::ALL {
+        assert!(registry::in_category(category).next().is_some());
+    }
+    let total: usize = Category::ALL
+        .into_iter()
+        .map(|c| registry::in_category(c).count())
+        .sum();
+    assert_eq!(total, registry::pages().len());
+}
+
+#[test]
+fn test_pages_are_named_in_every_language() {
+    for lang in Lang::ALL {
+        for page in registry::pages() {
+            assert!(!page.name(lang).is_empty());
+        }
+    }
+    let pipeline = registry::find("pipeline").unwrap();
+    assert_eq!(pipeline.name(Lang::En), "Recipe Pipeline");
+}
+
+#[test]
+fn test_sitemap_lists_every_page() {
+    let xml = registry::sitemap(SITE);
+    assert!(xml.contains("<loc>https://example.com/app/</loc>"));
+    for page in registry::pages() {
+        assert!(xml.contains(&format!("<loc>https://example.com/app/{}</loc>", page.slug)));
+    }
+    assert!(xml.trim_end().ends_with("</urlset>"));
+}
+
+#[test]
+fn test_seo_page_rewrites_meta_tags() {
+    let template = r#"<title>Home</title>
+<meta name="title" content="Home"/>
+<meta name="description" content="All tools"/>
+<meta property="og:title" content="Home"/>
+<meta property="og:url" content="https://example.com/app/"/>
+<meta property="twitter:description" content="All tools"/>
+<link rel="canonical" href="https://example.com/app/"/>"#;
+    let page = registry::find("json").unwrap();
+    let html = registry::seo_page(template, page, SITE);
+    assert!(html.contains("<title>JSON Formatter</title>"));
+    assert!(html.contains(r#"<meta name="title" content="JSON Formatter"/>"#));
+    assert!(html.contains(&format!(
+        r#"<meta name="description" content="{}"/>"#,
+        page.description
+    )));
+    assert!(html.contains(r#"<meta property="og:title" content="JSON Formatter"/>"#));
+    assert!(html.contains(r#"<meta property="og:url" content="https://example.com/app/json"/>"#));
+    assert!(html.contains(r#"<link rel="canonical" href="https://example.com/app/json"/>"#));
+    assert!(!html.contains("All tools"));
+}
+
+#[test]
+fn test_page_meta_is_localized() {
+    let json = registry::find("json").unwrap();
+    assert_eq!(json.localized_title(Lang::En), json.title);
+    assert_eq!(json.localized_description(Lang::En), json.description);
+    for page in registry::pages() {
+        for lang in [Lang::Zh, Lang::ZhHans, Lang::Ja] {
+            assert_ne!(page.localized_title(lang), page.title, "{}", page.slug);
+            assert_ne!(
+                page.localized_description(lang),
+                page.description,
+                "{}",
+                page.slug
+            );
+        }
+    }
+}
+
+#[test]
+fn test_document_title_and_description() {
+    let hash = registry::find("hash");
+    assert_eq!(
+        registry::document_title(hash, Lang::En),
+        "Hash Generator | Do Everything Like a God"
+    );
+    assert_eq!(
+        registry::document_title(None, Lang::Zh),
+        "做甚麼都有如神助 - 線上開發者工具箱"
+    );
+    assert!(!registry::document_description(None, Lang::Ja).is_empty());
+}
+
+#[test]
+fn test_page_url() {
+    assert_eq!(registry::page_url(SITE, None), "https://example.com/app/");
+    assert_eq!(
+        registry::page_url("https://example.com/app", registry::find("cron")),
+        "https://example.com/app/cron"
+    );
+}
@@ -0,0 +1,45 @@
+use do_everything_like_a_god::tools::search::{fuzzy_filter, fuzzy_score};
+
+#[test]
+fn test_fuzzy_score_requires_ordered_subsequence() {
+    assert!(fuzzy_score("b64", "Base64 base64").is_some());
+    assert!(fuzzy_score("BASE", "base32").is_some());
+    assert!(fuzzy_score("46b", "base64").is_none());
+    assert!(fuzzy_score("x", "base64").is_none());
+    assert_eq!(fuzzy_score("", "anything"), Some(0));
+}
+
+#[test]
+fn test_fuzzy_score_ignores_query_whitespace() {
+    assert_eq!(
+        fuzzy_score("url enc", "URL Encode url-escape"),
+        fuzzy_score("urlenc", "URL Encode url-escape")
+    );
+}
+
+#[test]
+fn test_fuzzy_score_prefers_word_starts_and_runs() {
+    let prefix = fuzzy_score("json", "JSON Tool json").unwrap();
+    let scattered = fuzzy_score("json", "Jwt Decoder signature on").unwrap();
+    assert!(prefix > scattered);
+}
+
+#[test]
+fn test_fuzzy_filter_orders_best_first() {
+    let candidates = [
+        "Base64 base64",
+        "Hash hash",
+        "Base58 base58",
+        "Switch theme",
+    ];
+    assert_eq!(fuzzy_filter("hash", &candidates), vec![1]);
+    assert_eq!(fuzzy_filter("b58", &candidates)[0], 2);
+    assert_eq!(fuzzy_filter("", &candidates), vec![0, 1, 2, 3]);
+    assert!(fuzzy_filter("zzz", &candidates).is_empty());
+}
+
+#[test]
+fn test_fuzzy_score_handles_unicode() {
+    assert!(fuzzy_score("工具", "Base64 工具 base64").is_some());
+    assert!(fuzzy_score("ß", "Straße").is_some());
+}
@@ -0,0 +1,135 @@
+use do_everything_like_a_god::tools::history::History;
+use do_everything_like_a_god::tools::permalink::State;
+use do_everything_like_a_god::tools::pipeline::{Recipe, Step};
+use do_everything_like_a_god::tools::settings::{
+    history_key, is_site_key, prefs_key, Bundle, ImportMode, BUNDLE_VERSION,
+};
+use do_everything_like_a_god::tools::{Options, ToolError};
+
+fn input(value: &str) -> State {
+    [("input".to_string(), value.to_string())].into()
+}
+
+fn storage() -> Vec<(String, String)> {
+    let mut history = History::default();
+    history.record(input("a"), String::new(), 1);
+    vec![
+        ("theme".into(), "light".into()),
+        ("lang".into(), "ja".into()),
+        (
+            "history-settings".into(),
+            r#"{"enabled":true,"tools":{"aes":true}}"#.into(),
+        ),
+        (
+            "recipes".into(),
+            serde_json::to_string(&[Recipe::new("r", vec![])]).unwrap(),
+        ),
+        (
+            history_key("base64"),
+            serde_json::to_string(&history).unwrap(),
+        ),
+        (prefs_key("base64"), r#"{"mode":"decode"}"#.into()),
+        ("someone-elses".into(), "keep".into()),
+        (history_key("json"), "not json".into()),
+    ]
+}
+
+#[test]
+fn test_site_keys() {
+    assert!(is_site_key("theme"));
+    assert!(is_site_key("history:base64"));
+    assert!(is_site_key("prefs:base32"));
+    assert!(!is_site_key("someone-elses"));
+}
+
+#[test]
+fn test_storage_round_trip() {
+    let bundle = Bundle::from_storage(storage());
+    assert_eq!(bundle.theme.as_deref(), Some("light"));
+    assert_eq!(bundle.lang.as_deref(), Some("ja"));
+    assert!(bundle.history_settings.enabled);
+    assert_eq!(bundle.recipes.len(), 1);
+    assert_eq!(bundle.prefs["base64"].get("mode"), Some("decode"));
+    // Unparseable values are skipped rather than failing the export.
+    assert_eq!(bundle.history.keys().collect::<Vec<_>>(), ["base64"]);
+
+    let written = bundle.to_storage();
+    assert!(written.iter().all(|(key, _)| is_site_key(key)));
+    assert_eq!(Bundle::from_storage(written), bundle);
+}
+
+#[test]
+fn test_json_round_trip() {
+    let bundle = Bundle::from_storage(storage());
+    let json = bundle.to_json();
+    assert!(json.contains(&format!("\"version\": {BUNDLE_VERSION}")));
+    assert_eq!(Bundle::from_json(&json).unwrap(), bundle);
+    assert_eq!(
+        Bundle::from_json(r#"{"version":1}"#).unwrap(),
+        Bundle::default()
+    );
+}
+
+#[test]
+fn test_validation() {
+    let newer = format!(r#"{{"version":{},"something":"new"}}"#, BUNDLE_VERSION + 1);
+    assert_eq!(
+        Bundle::from_json(&newer).unwrap_err(),
+        ToolError::UnsupportedVersion(BUNDLE_VERSION + 1)
+    );
+    assert!(matches!(
+        Bundle::from_json(r#"{"version":1,"typo":1}"#),
+        Err(ToolError::Json(_))
+    ));
+    assert!(matches!(
+        Bundle::from_json(r#"{"recipes":[]}"#),
+        Err(ToolError::Json(_))
+    ));
+    assert!(matches!(
+        Bundle::from_json(r#"{"version":1,"lang":"xx"}"#),
+        Err(ToolError::InvalidOption { .. })
+    ));
+    assert_eq!(
+        Bundle::from_json(r#"{"version":1,"history":{"nope":[]}}"#).unwrap_err(),
+        ToolError::UnknownTool("nope".into())
+    );
+    assert!(matches!(
+        Bundle::from_json(r#"{"version":1,"prefs":{"base64":{"mode":"sideways"}}}"#),
+        Err(ToolError::InvalidOption { .. })
+    ));
+    let bad_recipe = r#"{"version":1,"recipes":[{"version":1,"steps":[{"tool":"nope"}]}]}"#;
+    assert_eq!(
+        Bundle::from_json(bad_recipe).unwrap_err(),
+        ToolError::UnknownTool("nope".into())
+    );
+}
+
+#[test]
+fn test_merge_and_replace() {
+    let current = Bundle::from_storage(storage());
+
+    let mut history = History::default();
+    history.record(input("b"), String::new(), 2);
+    let imported = Bundle {
+        lang: Some("en".into()),
+        recipes: vec![
+            Recipe::new("r", vec![Step::new("json", Options::new())]),
+            Recipe::new("s", vec![]),
+        ],
+        history: [("base64".to_string(), history)].into(),
+        ..Bundle::default()
+    };
+
+    let merged = current.clone().import(imported.clone(), ImportMode::Merge);
+    assert_eq!(merged.theme.as_deref(), Some("light"));
+    assert_eq!(merged.lang.as_deref(), Some("en"));
+    assert_eq!(merged.recipes.len(), 2);
+    assert_eq!(merged.recipes[0].steps.len(), 1);
+    assert_eq!(merged.prefs, current.prefs);
+    let entries = merged.history["base64"].entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].state, input("b"));
+
+    let replaced = current.import(imported.clone(), ImportMode::Replace);
+    assert_eq!(replaced, imported);
+}
@@ -0,0 +1,78 @@
+use do_everything_like_a_god::tools::{self, Lang, Options, Output};
+
+#[test]
+fn test_registry_ids_are_unique() {
+    let mut ids = std::collections::HashSet::new();
+    for tool in tools::all() {
+        assert!(ids.insert(tool.id()), "Duplicate tool id: {}", tool.id());
+        assert!(!tool.name(Lang::En).is_empty());
+        assert!(!tool.name(Lang::Zh).is_empty());
+        assert_eq!(tools::find(tool.id()).unwrap().id(), tool.id());
+    }
+    assert!(tools::find("missing").is_none());
+}
+
+#[test]
+fn test_default_options_are_valid() {
+    for tool in tools::all() {
+        for spec in tool.options() {
+            assert!(
+                Options::new().value(spec).is_ok(),
+                "{}:{}",
+                tool.id(),
+                spec.key
+            );
+        }
+    }
+}
+
+#[test]
+fn test_run_encode_decode_modes() {
+    let base64 = tools::find("base64").unwrap();
+    let encoded = base64.run(b"GOD MODE", &Options::new()).unwrap();
+    assert_eq!(encoded, Output::Text("R09EIE1PREU=".into()));
+
+    let decoded = base64
+        .run(b"R09EIE1PREU=", &Options::new().with("mode", "decode"))
+        .unwrap();
+    assert_eq!(decoded, Output::Bytes(b"GOD MODE".to_vec()));
+}
+
+#[test]
+fn test_run_rejects_unknown_choice() {
+    let err = tools::find("base64")
+        .unwrap()
+        .run(b"x", &Options::new().with("mode", "rot13"))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Invalid value for option mode: rot13");
+}
+
+#[test]
+fn test_run_multi_field_outputs() {
+    let hash = tools::find("hash").unwrap();
+    match hash.run(b"", &Options::new()).unwrap() {
+        Output::Fields(fields) => {
+            assert_eq!(fields.len(), 5);
+            assert_eq!(
+                fields[0],
+                ("MD5".into(), "d41d8cd98f00b204e9800998ecf8427e".into())
+            );
+        }
+        other => panic!("Unexpected output: {:?}", other),
+    }
+
+    let sha256 = hash
+        .run(b"", &Options::new().with("alg", "sha256"))
+        .unwrap();
+    assert_eq!(
+        sha256.to_text(),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+
+    let conv = tools::find("base-conv").unwrap();
+    let out = conv.run(b"255", &Options::new()).unwrap();
+    assert_eq!(
+        out.to_text(),
+        "Decimal: 255\nHex: ff\nBinary: 11111111\nOctal: 377"
+    );
+}
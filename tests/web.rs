@@ -1,4 +1,9 @@
+// The tests keep the `let _ = mount_to_body(App)` form they were written in.
+#![allow(clippy::let_unit_value)]
+
 use do_everything_like_a_god::app::*;
+use do_everything_like_a_god::i18n::Lang;
+use do_everything_like_a_god::registry;
 use leptos::*;
 use wasm_bindgen_test::*;
 
@@ -12,6 +17,14 @@ fn get_body() -> web_sys::HtmlElement {
     get_document().body().unwrap()
 }
 
+fn option_values(select: &web_sys::Element) -> Vec<String> {
+    let options = select.get_elements_by_tag_name("option");
+    (0..options.length())
+        .filter_map(|i| options.item(i))
+        .filter_map(|option| option.get_attribute("value"))
+        .collect()
+}
+
 #[wasm_bindgen_test]
 fn test_app_initialization() {
     let _ = create_runtime();
@@ -39,41 +52,30 @@ fn test_brand_and_menu() {
 }
 
 #[wasm_bindgen_test]
-fn test_language_toggle_button() {
+fn test_language_select() {
     let _ = create_runtime();
     let _ = mount_to_body(App);
-    let body = get_body();
-    let buttons = body.get_elements_by_tag_name("button");
-    let mut has_lang = false;
-    for i in 0..buttons.length() {
-        if let Some(btn) = buttons.item(i) {
-            let text = btn.text_content().unwrap_or_default();
-            if text.contains("中文") || text.contains("EN") {
-                has_lang = true;
-                break;
-            }
-        }
-    }
-    assert!(has_lang);
+    let select = get_document()
+        .query_selector("select[aria-label=\"Language\"]")
+        .unwrap()
+        .expect("language select");
+    assert_eq!(
+        option_values(&select),
+        Lang::ALL.map(|l| l.code().to_string())
+    );
 }
 
 #[wasm_bindgen_test]
-fn test_theme_toggle_button() {
+fn test_theme_select() {
     let _ = create_runtime();
     let _ = mount_to_body(App);
-    let body = get_body();
-    let buttons = body.get_elements_by_tag_name("button");
-    let mut has_theme = false;
-    for i in 0..buttons.length() {
-        if let Some(btn) = buttons.item(i) {
-            let text = btn.text_content().unwrap_or_default();
-            if text.contains("☀️") || text.contains("🌙") {
-                has_theme = true;
-                break;
-            }
-        }
-    }
-    assert!(has_theme);
+    let select = get_document()
+        .query_selector("select[aria-label=\"Theme\"]")
+        .unwrap()
+        .expect("theme select");
+    let values = option_values(&select);
+    assert_eq!(values, Theme::ALL.map(|t| t.code().to_string()));
+    assert!(values.iter().any(|v| v == "system"));
 }
 
 #[wasm_bindgen_test]
@@ -92,21 +94,9 @@ fn test_all_tool_pages_exist() {
     let body = get_body();
     let html = body.inner_html();
 
-    assert!(html.contains("Base64"));
-    assert!(html.contains("Base32"));
-    assert!(html.contains("Base58"));
-    assert!(html.contains("Hash"));
-    assert!(html.contains("AES"));
-    assert!(html.contains("JWT"));
-    assert!(html.contains("JSON"));
-    assert!(html.contains("Regex"));
-    assert!(html.contains("Diff"));
-    assert!(html.contains("UUID"));
-    assert!(html.contains("Timestamp"));
-    assert!(html.contains("Base Conv"));
-    assert!(html.contains("Color"));
-    assert!(html.contains("Cron"));
-    assert!(html.contains("Image Base64"));
+    for page in registry::pages() {
+        assert!(html.contains(page.label), "missing {}", page.slug);
+    }
 }
 
 #[wasm_bindgen_test]